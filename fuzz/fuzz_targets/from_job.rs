@@ -0,0 +1,24 @@
+#![no_main]
+
+use std::sync::Arc;
+use libfuzzer_sys::fuzz_target;
+use verifier::instance_base::InstanceBase;
+use verifier::parsers::file_reader::Source;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+//Parses the framework and proof contents, which must either succeed or return a parse error
+fuzz_target!(|input: (u8, Vec<u8>, Option<Vec<u8>>, Vec<u8>)| {
+    let (semantics, framework, required, proof) = input;
+    let semantics = match semantics % 3 {
+        0 => Semantics::ConflictFree,
+        1 => Semantics::Admissible,
+        _ => Semantics::Stable
+    };
+
+    let job = VerificationJob {
+        required_arguments: required.map(|required| Source::Content(Arc::from(required))),
+        ..VerificationJob::new(Source::Content(Arc::from(framework)), Source::Content(Arc::from(proof)), semantics)
+    };
+    let _ = InstanceBase::from_job(&job, None);
+});
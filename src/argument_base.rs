@@ -10,7 +10,7 @@ pub struct ArgumentBase {
 
 impl ArgumentBase {
 
-    pub fn new<'a>() -> ArgumentBase {
+    pub fn new() -> ArgumentBase {
         ArgumentBase {
             id: 0,
             attacked_by: Vec::new()
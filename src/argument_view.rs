@@ -4,6 +4,8 @@ use std::collections::{HashSet, VecDeque};
 pub struct ArgumentView {
     iteration: usize,
     value: bool,
+    /// The clause that propagated the current value, if any.
+    reason: Option<usize>,
     positive_watched: HashSet<usize>,
     negative_watched: HashSet<usize>,
 }
@@ -13,6 +15,7 @@ impl ArgumentView {
         ArgumentView {
             iteration: 0,
             value: true,
+            reason: None,
             positive_watched: HashSet::new(),
             negative_watched: HashSet::new()
         }
@@ -38,9 +41,10 @@ impl ArgumentView {
         }
     }
 
-    pub fn set_value(&mut self, value: bool, iteration: usize, clauses_to_check: &mut VecDeque<usize>) {
+    pub fn set_value(&mut self, value: bool, reason: Option<usize>, iteration: usize, clauses_to_check: &mut VecDeque<usize>) {
         self.iteration = iteration;
         self.value = value;
+        self.reason = reason;
 
         let hash_map = match value {
             true => &mut self.negative_watched,
@@ -61,4 +65,15 @@ impl ArgumentView {
             None
         }
     }
+
+    /// Returns the clause that propagated the current value, which is None if the argument is unassigned or has been assigned directly.
+    #[inline]
+    pub fn get_reason(&self, iteration: usize) -> Option<usize> {
+        if self.iteration == iteration {
+            self.reason
+        }
+        else {
+            None
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,326 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Arc, Condvar, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use clap::ValueEnum;
+use serde::Deserialize;
+use signal_hook::consts::{SIGINT, SIGTERM, SIGUSR2};
+use signal_hook::iterator::Signals;
+use crate::parsers::file_reader::{FileReader, Source};
+use crate::parsers::format::FrameworkFormat;
+use crate::parsers::parse_error::ParseError;
+use crate::semantics::Semantics;
+use crate::supervisor::{Supervisor, SupervisorState};
+use crate::{get_result_message, resume_all, should_stop, stop_all, verify_job, VerificationJob};
+
+/// The verdict of a single verification.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    /// The proof was verified successfully.
+    Verified,
+    /// The proof failed to verify.
+    Failed,
+    /// One of the input files could not be parsed.
+    Invalid,
+    /// The timeout was reached.
+    Timeout,
+    /// The verification terminated for any other reason.
+    Error
+}
+
+impl Verdict {
+
+    /// Determines the verdict of a terminated verification together with details about it.
+    pub fn from_supervisor(supervisor: &Supervisor) -> (Verdict, String) {
+        match supervisor.get_state() {
+            SupervisorState::Finished | SupervisorState::RequiredArgumentInconsistent => {
+                match supervisor.get_result() {
+                    Some((true, _)) if supervisor.get_state() == SupervisorState::RequiredArgumentInconsistent => (Verdict::Verified, "The required arguments are inconsistent.".to_string()),
+                    Some((true, _)) => (Verdict::Verified, String::new()),
+                    Some((false, Some(clause_index))) => match supervisor.get_instance() {
+                        Some(instance) => (Verdict::Failed, format!("The {} failed to verify.", instance.describe_clause(clause_index))),
+                        None => (Verdict::Error, "Failed to get result.".to_string())
+                    },
+                    Some((false, None)) => (Verdict::Failed, "The empty clause failed to verify.".to_string()),
+                    None => (Verdict::Error, "Failed to get result.".to_string())
+                }
+            },
+            SupervisorState::ParsingFailed => (Verdict::Invalid, supervisor.get_parsing_error().to_string()),
+            SupervisorState::TimedOut => (Verdict::Timeout, String::new()),
+            _ => (Verdict::Error, get_result_message(supervisor).0)
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Verified => write!(f, "verified"),
+            Verdict::Failed => write!(f, "failed"),
+            Verdict::Invalid => write!(f, "invalid"),
+            Verdict::Timeout => write!(f, "timeout"),
+            Verdict::Error => write!(f, "error")
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "verified" => Ok(Verdict::Verified),
+            "failed" => Ok(Verdict::Failed),
+            "invalid" => Ok(Verdict::Invalid),
+            "timeout" => Ok(Verdict::Timeout),
+            "error" => Ok(Verdict::Error),
+            _ => Err(format!("The verdict '{}' is invalid", s))
+        }
+    }
+}
+
+/// A job of a batch verification together with the verdict it is expected to yield.
+pub struct BatchJob {
+    /// The files and semantics to verify.
+    pub job: VerificationJob,
+    /// The expected verdict or None if any verdict is acceptable.
+    pub expected: Option<Verdict>
+}
+
+/// The result of a job of a batch verification.
+pub struct BatchResult {
+    /// The verdict of the verification.
+    pub verdict: Verdict,
+    /// Details about the verdict, e.g. which clause failed to verify.
+    pub details: String,
+    /// The time the verification took.
+    pub duration: Duration
+}
+
+impl BatchResult {
+
+    /// Whether or not the verdict differs from the expected verdict of the job.
+    pub fn is_mismatch(&self, job: &BatchJob) -> bool {
+        job.expected.is_some_and(|expected| expected != self.verdict)
+    }
+}
+
+/// A line of a JSON lines manifest.
+#[derive(Deserialize)]
+struct ManifestEntry {
+    instance: PathBuf,
+    format: Option<String>,
+    description: Option<PathBuf>,
+    required: Option<PathBuf>,
+    proof: PathBuf,
+    semantics: String,
+    expected: Option<String>
+}
+
+/// The header of a CSV manifest.
+const CSV_HEADER: &str = "instance,description,required,proof,semantics,expected";
+
+/// Parses a manifest of batch jobs. Every line is either a JSON object or contains the comma separated columns of CSV_HEADER, where the optional header line as well as the description, required and expected columns may be omitted.
+/// Relative paths are interpreted relative to the directory that contains the manifest.
+pub fn parse_manifest(manifest_path: &PathBuf) -> Result<Vec<BatchJob>, ParseError> {
+    let base_path = manifest_path.parent().map(Path::to_path_buf).unwrap_or_default();
+    let manifest_reader = FileReader::new(manifest_path, false)?;
+    let file = manifest_reader.get_name().to_string();
+    let mut jobs = Vec::new();
+
+    manifest_reader.for_each_line(|line_number, line| {
+
+        should_stop()?;
+
+        let error = |message: String| ParseError::at(&file, line_number, line, None, message);
+
+        let trimmed_line = line.trim();
+        if trimmed_line.is_empty() || trimmed_line == CSV_HEADER {
+            return Ok(());
+        }
+
+        let entry = if trimmed_line.starts_with('{') {
+            match serde_json::from_str::<ManifestEntry>(trimmed_line) {
+                Ok(entry) => entry,
+                Err(err) => return Err(ParseError { column: Some(err.column() + ParseError::get_column(line, trimmed_line) - 1), ..error(format!("The manifest line '{}' is malformed: {}", line, err)) })
+            }
+        }
+        else {
+            let split : Vec<&str> = trimmed_line.split(',').map(str::trim).collect();
+            let optional = |index: usize| split.get(index).filter(|value| !value.is_empty()).map(|value| value.to_string());
+            match split.len() {
+                3 => ManifestEntry { instance: PathBuf::from(split[0]), format: None, description: None, required: None, proof: PathBuf::from(split[1]), semantics: split[2].to_string(), expected: None },
+                5 | 6 => ManifestEntry {
+                    instance: PathBuf::from(split[0]),
+                    format: None,
+                    description: optional(1).map(PathBuf::from),
+                    required: optional(2).map(PathBuf::from),
+                    proof: PathBuf::from(split[3]),
+                    semantics: split[4].to_string(),
+                    expected: optional(5)
+                },
+                _ => return Err(error(format!("The manifest line '{}' is malformed", line)))
+            }
+        };
+
+        let semantics = match Semantics::from_str(&entry.semantics, true) {
+            Ok(semantics) => semantics,
+            Err(_) => return Err(error(format!("The manifest line '{}' references the semantics '{}' that is invalid", line, entry.semantics)))
+        };
+
+        let format = match &entry.format {
+            Some(name) => match FrameworkFormat::from_str(name, true) {
+                Ok(format) => Some(format),
+                Err(_) => return Err(error(format!("The manifest line '{}' references the format '{}' that is invalid", line, name)))
+            },
+            None => None
+        };
+
+        let expected = match entry.expected {
+            Some(expected) => Some(Verdict::from_str(&expected).map_err(|message| error(format!("{} in the manifest line '{}'", message, line)))?),
+            None => None
+        };
+
+        jobs.push(BatchJob {
+            job: VerificationJob {
+                format,
+                description: entry.description.map(|path| Source::Path(base_path.join(path))),
+                required_arguments: entry.required.map(|path| Source::Path(base_path.join(path))),
+                ..VerificationJob::new(Source::Path(base_path.join(entry.instance)), Source::Path(base_path.join(entry.proof)), semantics)
+            },
+            expected
+        });
+        Ok(())
+    })?;
+
+    Ok(jobs)
+}
+
+/// Verifies the provided jobs, running up to parallel_jobs verifications at the same time with the provided number of threads each.
+/// While the verification is running, SIGUSR2 can be sent to print the number of finished jobs to stderr. If a progress interval is provided, it is additionally printed in this interval.
+pub fn verify_batch(jobs: &[BatchJob], parallel_jobs: u16, number_of_threads: u16, complete: bool, timeout: Option<Duration>, progress: Option<Duration>) -> Result<Vec<BatchResult>, String> {
+
+    //Setup signal handlers
+    let mut signals = Signals::new([SIGINT, SIGTERM, SIGUSR2]).map_err(|err| format!("Failed to setup signal handlers: {}", err))?;
+    let signals_handle = signals.handle();
+
+    let next_job = AtomicUsize::new(0);
+    let finished_jobs = AtomicUsize::new(0);
+    let results : Mutex<Vec<Option<BatchResult>>> = Mutex::new(jobs.iter().map(|_| None).collect());
+    let terminated = (Mutex::new(false), Condvar::new());
+
+    thread::scope(|scope| {
+
+        // Handle signals
+        scope.spawn(|| {
+            for sig in signals.forever() {
+                match sig {
+                    SIGUSR2 => eprintln!("Progress: {} of {} jobs finished", finished_jobs.load(Ordering::Acquire), jobs.len()),
+                    _ => stop_all() //Interrupted, the running verifications stop themselves
+                }
+            }
+        });
+
+        // Report the progress periodically
+        if let Some(interval) = progress {
+            let (terminated, finished_jobs) = (&terminated, &finished_jobs);
+            scope.spawn(move || {
+                let (mutex, cvar) = terminated;
+                let mut guard = mutex.lock().unwrap();
+                loop {
+                    guard = cvar.wait_timeout_while(guard, interval, |terminated| !*terminated).unwrap().0;
+                    if *guard {
+                        break;
+                    }
+                    eprintln!("Progress: {} of {} jobs finished", finished_jobs.load(Ordering::Acquire), jobs.len());
+                }
+            });
+        }
+
+        let runners : Vec<_> = (0..parallel_jobs).map(|_| scope.spawn(|| {
+            while should_stop().is_ok() {
+                let index = next_job.fetch_add(1, Ordering::AcqRel);
+                if index >= jobs.len() {
+                    break;
+                }
+
+                let start_time = Instant::now();
+                let supervisor = Arc::new(Supervisor::new(number_of_threads));
+                verify_job(&supervisor, jobs[index].job.clone(), None, complete, timeout);
+                let duration = start_time.elapsed();
+
+                let (verdict, details) = Verdict::from_supervisor(&supervisor);
+                if let Ok(mut results) = results.lock() {
+                    results[index] = Some(BatchResult { verdict, details, duration });
+                }
+                finished_jobs.fetch_add(1, Ordering::AcqRel);
+            }
+        })).collect();
+
+        for runner in runners {
+            _ = runner.join();
+        }
+        signals_handle.close();
+        let (mutex, cvar) = &terminated;
+        *mutex.lock().unwrap() = true;
+        cvar.notify_all();
+    });
+    resume_all(); //The interruption only applies to this batch
+
+    let results = results.into_inner().map_err(|_| "Failed to collect the results.".to_string())?;
+    Ok(results.into_iter().map(|result| result.unwrap_or(BatchResult {
+        verdict: Verdict::Error,
+        details: "Not verified due to an interruption.".to_string(),
+        duration: Duration::ZERO
+    })).collect())
+}
+
+/// Writes a summary table of the results, in which verdicts that differ from the expected ones are marked. Returns the number of mismatches.
+pub fn write_summary(jobs: &[BatchJob], results: &[BatchResult], writer: &mut dyn Write) -> std::io::Result<usize> {
+    let header = ["#", "Match", "Verdict", "Expected", "Semantics", "Time", "Proof", "Details"];
+    let mut rows = Vec::with_capacity(jobs.len());
+    let mut mismatches = 0;
+
+    for (index, (job, result)) in jobs.iter().zip(results).enumerate() {
+        let mismatch = result.is_mismatch(job);
+        if mismatch {
+            mismatches += 1;
+        }
+
+        rows.push([
+            (index + 1).to_string(),
+            match job.expected { None => "-".to_string(), Some(_) if mismatch => "MISMATCH".to_string(), Some(_) => "ok".to_string() },
+            result.verdict.to_string(),
+            job.expected.map_or("-".to_string(), |expected| expected.to_string()),
+            job.job.semantics.to_possible_value().map_or(String::new(), |value| value.get_name().to_string()),
+            format!("{:.3}s", result.duration.as_secs_f64()),
+            job.job.proof.to_string(),
+            result.details.clone()
+        ]);
+    }
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, column) in widths.iter_mut().zip(row) {
+            *width = (*width).max(column.len());
+        }
+    }
+
+    let write_row = |writer: &mut dyn Write, row: &[&str]| -> std::io::Result<()> {
+        let line : Vec<String> = row.iter().zip(widths).map(|(column, width)| format!("{:width$}", column, width = width)).collect();
+        writeln!(writer, "{}", line.join("  ").trim_end())
+    };
+
+    write_row(writer, &header)?;
+    for row in &rows {
+        write_row(writer, &row.each_ref().map(String::as_str))?;
+    }
+
+    writeln!(writer)?;
+    writeln!(writer, "{} jobs, {} mismatches.", jobs.len(), mismatches)?;
+    Ok(mismatches)
+}
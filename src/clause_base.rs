@@ -1,18 +1,18 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use crate::semantics::VerifierType;
+use crate::witness::Witness;
 
 /// The clauses of the instance / proof.
+#[derive(Clone)]
 pub struct ClauseBase {
     /// The index of the clause
     index: usize,
     /// The IDs of the arguments contained in the clause and their sign.
     members: Vec<(usize, bool)>,
-    /// The clause index at which point this clause was deleted an is no longer valid.
-    deleted_at: Option<usize>,
     /// The function used to verify this clause.
     verifier: Option<VerifierType>,
-    /// Whether or not the clause has been used during verification.
-    used: AtomicBool,
+    /// The witness of the clause if it is redundant by propagation or substitution.
+    witness: Option<Box<Witness>>
 }
 
 impl ClauseBase {
@@ -21,9 +21,8 @@ impl ClauseBase {
         ClauseBase {
             index,
             members: Vec::new(),
-            deleted_at: None,
             verifier: None,
-            used: AtomicBool::new(false)
+            witness: None
         }
     }
 
@@ -49,8 +48,13 @@ impl ClauseBase {
     }
 
     #[inline]
-    pub fn set_deleted_at(&mut self, index: usize) {
-        self.deleted_at = Some(index);
+    pub fn set_witness(&mut self, witness: Witness) {
+        self.witness = Some(Box::new(witness));
+    }
+
+    #[inline]
+    pub fn get_witness(&self) -> Option<&Witness> {
+        self.witness.as_deref()
     }
 
     #[inline]
@@ -68,14 +72,109 @@ impl ClauseBase {
         self.members[index]
     }
 
+    /// Returns a hash of the set of members, which does not depend on their order.
+    /// Members are unique, except that the attacked argument of an attack may also be one of its attackers, which is only counted once.
+    pub fn get_literal_set_hash(&self) -> u64 {
+        let first = self.members.first();
+        self.members.iter().enumerate()
+            .filter(|(index, member)| *index == 0 || Some(*member) != first)
+            .fold(0_u64, |hash, (_, (argument, sign))| hash.wrapping_add(Self::mix((*argument as u64) << 1 | *sign as u64)))
+    }
+
+    /// Returns whether or not both clauses have the same set of members, regardless of their order.
+    pub fn has_same_literal_set(&self, other: &ClauseBase) -> bool {
+        const MAX_LINEAR_SIZE: usize = 32;
+        if self.members.len() <= MAX_LINEAR_SIZE && other.members.len() <= MAX_LINEAR_SIZE {
+            return self.members.iter().all(|member| other.members.contains(member)) && other.members.iter().all(|member| self.members.contains(member));
+        }
+
+        //Compare the sorted members of large clauses to avoid a quadratic number of comparisons
+        let sorted = |members: &Vec<(usize, bool)>| {
+            let mut sorted = members.clone();
+            sorted.sort_unstable();
+            sorted.dedup();
+            sorted
+        };
+        sorted(&self.members) == sorted(&other.members)
+    }
+
+    /// Scrambles the bits of a single member for get_literal_set_hash (the finalizer of splitmix64).
     #[inline]
-    pub fn is_used(&self) -> bool {
-        self.used.load(Ordering::Acquire)
+    fn mix(value: u64) -> u64 {
+        let mut value = value.wrapping_add(0x9e3779b97f4a7c15);
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    /// Returns the line of an attack in the instance file format, i.e. the attacked argument followed by the attackers.
+    pub fn to_attack_line(&self) -> String {
+        let mut line = String::new();
+        for (argument, _) in &self.members {
+            line.push_str(&(argument + 1).to_string());
+            line.push(' ');
+        }
+        line.push('0');
+        line
+    }
+
+    /// Returns the members of a proof clause in the proof file format, i.e. the signed argument numbers followed by 0.
+    pub fn to_members_line(&self) -> String {
+        let mut line = String::new();
+        Self::push_members(&self.members, &mut line);
+        line.push('0');
+        line
+    }
+
+    /// Returns the line of a proof clause in the proof file format including the prefix of its verifier and its witness, whose assignment follows the members.
+    pub fn to_proof_line(&self) -> String {
+        match (&self.verifier, &self.witness) {
+            (Some(VerifierType::Admissibility(Some(attack_index))), _) => format!("i{} {}", attack_index, self.to_members_line()),
+            (Some(VerifierType::Admissibility(None)) | Some(VerifierType::Stability), _) => format!("i {}", self.to_members_line()),
+            (_, Some(witness)) => {
+                let mut line = witness.to_permutation_prefix().map(|prefix| prefix + " ").unwrap_or_default();
+                Self::push_members(&self.members, &mut line);
+                Self::push_members(witness.get_assignment(), &mut line);
+                line.push('0');
+                line
+            },
+            _ => self.to_members_line()
+        }
+    }
+
+    /// Appends the signed argument numbers of the members to the line, each followed by a space.
+    fn push_members(members: &[(usize, bool)], line: &mut String) {
+        for (argument, sign) in members {
+            if !*sign {
+                line.push('-');
+            }
+            line.push_str(&(argument + 1).to_string());
+            line.push(' ');
+        }
+    }
+
+}
+
+/// The state of a clause with respect to the proof that is verified. Kept apart from the clause, as the attacks are shared between the instances of multiple proofs.
+pub struct ClauseState {
+    /// The clause index at which point this clause was deleted an is no longer valid.
+    deleted_at: Option<usize>,
+    /// Whether or not the clause has been used during verification.
+    used: AtomicBool,
+}
+
+impl ClauseState {
+
+    pub fn new() -> ClauseState {
+        ClauseState {
+            deleted_at: None,
+            used: AtomicBool::new(false)
+        }
     }
 
     #[inline]
-    pub fn set_used(&self) -> bool {
-        !self.used.swap(true, Ordering::AcqRel)
+    pub fn set_deleted_at(&mut self, index: usize) {
+        self.deleted_at = Some(index);
     }
 
     #[inline]
@@ -83,6 +182,16 @@ impl ClauseBase {
         self.deleted_at
     }
 
+    #[inline]
+    pub fn is_used(&self) -> bool {
+        self.used.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    pub fn set_used(&self) -> bool {
+        !self.used.swap(true, Ordering::AcqRel)
+    }
+
 }
 
 
@@ -2,16 +2,19 @@ use crate::clause_base::ClauseBase;
 use crate::clause_view::WatchUpdateResult::*;
 use crate::instance_view::InstanceView;
 use crate::semantics::VerifierType;
+use crate::witness::Witness;
 
 /// Represents a worker threads view of a clause, with its current watches for this thread.
-
 pub struct ClauseView<'a> {
     base: &'a ClauseBase,
     watches: [usize; 2]
 }
 
+/// A watch update, i.e. the new watch position, the argument that is no longer watched and the argument that is now watched.
+type WatchUpdate = (usize, (usize, bool), (usize, bool));
+
 enum WatchUpdateResult {
-    Success((usize,(usize, bool), (usize, bool))),
+    Success(WatchUpdate),
     AlreadySatisfied,
     Failed
 }
@@ -42,7 +45,12 @@ impl ClauseView<'_> {
           self.base.get_verifier()
     }
 
-    pub fn check_propagation(&self, instance: &InstanceView) -> (Option<(usize, bool)>, Option<(usize, (usize, bool), (usize, bool))> , Option<(usize, (usize, bool), (usize, bool))>) {
+    #[inline]
+    pub fn get_witness(&self) -> Option<&Witness> {
+        self.base.get_witness()
+    }
+
+    pub fn check_propagation(&self, instance: &InstanceView) -> (Option<(usize, bool)>, Option<WatchUpdate>, Option<WatchUpdate>) {
 
         if self.base.get_number_of_members() == 1 {
             return (Some(self.base.get_member(0)), None, None);
@@ -71,8 +79,8 @@ impl ClauseView<'_> {
 
         //Both watches are not set to the target value if we are here
         //Update every watch that points to an argument that is already set
-        let mut first_watch_update: Option<(usize, (usize, bool), (usize, bool))> = None;
-        let mut second_watch_update: Option<(usize, (usize, bool), (usize, bool))> = None;
+        let mut first_watch_update: Option<WatchUpdate> = None;
+        let mut second_watch_update: Option<WatchUpdate> = None;
 
         if first_watch_value_set {
             match self.update_watch(instance, self.watches[0], first_watch_index, first_watch_sign, self.watches[1]) {
@@ -128,14 +136,9 @@ impl ClauseView<'_> {
         self.watches[watch] = index;
     }
 
-    #[inline]
-    pub fn deleted_at(&self) -> Option<usize> {
-        self.base.deleted_at()
-    }
-
     #[inline]
     pub fn get_members(&self) -> &Vec<(usize, bool)> {
-        &self.base.get_members()
+        self.base.get_members()
     }
 
 
@@ -0,0 +1,73 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use crate::instance_base::InstanceBase;
+use crate::supervisor::{Supervisor, SupervisorState};
+use crate::{get_result_message, EXIT_CODE_FAILURE};
+
+/// The result of a core minimization.
+pub struct MinimizedCore {
+    /// The indices of the attacks of the core in ascending order.
+    pub attacks: Vec<usize>,
+    /// Whether or not no attack can be removed from the core such that the proof is still verified, which is not the case if the timeout has been reached.
+    pub minimal: bool,
+    /// The number of verifications of the proof that were required.
+    pub verifications: usize
+}
+
+/// Minimizes the attacks that are used by the proof of the instance, such that the result is a set of attacks for which the proof is still verified, but not after removing any of them.
+/// The core is thus only minimal for the supplied proof: Another proof may show that a smaller set of attacks is inconsistent with the required arguments.
+/// The proof is verified first, then every attack of the core is removed from the framework in turn and the proof is verified again. If it is still verified, the attacks used by this verification become the new core. This is repeated until no attack of the core can be removed.
+/// Returns the result message and exit code if the proof cannot be verified for the instance.
+pub fn minimize_core(number_of_threads: u16, instance: InstanceBase, complete: bool, timeout: Option<Duration>) -> Result<MinimizedCore, (String, u8)> {
+    let deadline = timeout.map(|timeout| Instant::now() + timeout);
+    let original = verify_instance(number_of_threads, instance, complete, deadline);
+    match original.get_state() {
+        SupervisorState::Finished if original.get_result() == Some((true, None)) => {},
+        SupervisorState::Finished => return Err(("The proof could not be verified, thus there is no core to minimize.".to_string(), EXIT_CODE_FAILURE)),
+        SupervisorState::RequiredArgumentInconsistent => return Ok(MinimizedCore { attacks: Vec::new(), minimal: true, verifications: 1 }),
+        _ => return Err(get_result_message(&original))
+    }
+
+    let instance = original.get_instance().unwrap();
+    let mut core = instance.get_used_attacks();
+    let mut verifications = 1;
+    loop {
+        let mut changed = false;
+        let mut position = 0;
+        while position < core.len() {
+            let candidate = core[position];
+            let removed : Vec<usize> = (0..instance.proof_start).filter(|attack| *attack == candidate || core.binary_search(attack).is_err()).collect();
+            let supervisor = verify_instance(number_of_threads, instance.without_attacks(&removed), complete, deadline);
+            verifications += 1;
+            match supervisor.get_state() {
+                SupervisorState::Finished if supervisor.get_result() == Some((true, None)) => {
+                    //The attacks used without the candidate are a subset of the core, the ones before the candidate have already been checked in this pass
+                    core = supervisor.get_instance().unwrap().get_used_attacks();
+                    position = core.partition_point(|attack| *attack < candidate);
+                    changed = true;
+                },
+                SupervisorState::Finished => position += 1, //The candidate is needed
+                SupervisorState::TimedOut => return Ok(MinimizedCore { attacks: core, minimal: false, verifications }),
+                _ => return Err(get_result_message(&supervisor))
+            }
+        }
+
+        //The attacks that were needed before other attacks were removed are checked again
+        if !changed {
+            break;
+        }
+    }
+
+    Ok(MinimizedCore { attacks: core, minimal: true, verifications })
+}
+
+/// Verifies the instance and waits until the verification has terminated, or stops it with the state TimedOut once the deadline has been reached.
+fn verify_instance(number_of_threads: u16, instance: InstanceBase, complete: bool, deadline: Option<Instant>) -> Arc<Supervisor> {
+    let supervisor = Arc::new(Supervisor::new(number_of_threads));
+    supervisor.start_with_instance(instance, complete);
+    if !supervisor.wait(deadline.map(|deadline| deadline.saturating_duration_since(Instant::now()))) {
+        supervisor.stop(SupervisorState::TimedOut);
+        supervisor.join_workers();
+    }
+    supervisor
+}
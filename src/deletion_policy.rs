@@ -0,0 +1,13 @@
+use clap::ValueEnum;
+
+///Determines how clause deletions in proofs are handled during verification.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum DeletionPolicy {
+    ///Every deletion is honoured, i.e. deleted clauses are not used by the following clauses.
+    #[default]
+    HonourAll,
+    ///Deletions of unit clauses are ignored like drat-trim does by default, all others are honoured.
+    IgnoreUnits,
+    ///Every deletion is honoured, but a warning is reported if a deleted clause would have propagated an argument while verifying a following clause.
+    WarnReasons
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::Write;
+use crate::deletion_policy::DeletionPolicy;
+use crate::framework_base::FrameworkBase;
+use crate::instance_base::InstanceBase;
+use crate::semantics::Semantics;
+
+/// The encoding of the extensions of a semantics as CNF in the DIMACS format, which consists of the attacks, the clauses of the semantics that are checked implicitly by the verifier and the required arguments as unit clauses.
+/// The variable of the argument i is i. For the semantics Admissible and Stable, the auxiliary variable n + k + 1 of the attack with (0-based) index k implies that all attackers of the attack are contained, where n is the number of arguments.
+/// Any other variable of an instance, i.e. of the extension constraint or an extension variable of the proof, is numbered after the auxiliary variables in the same order.
+pub struct DimacsEncoding {
+    number_of_arguments: usize,
+    number_of_auxiliary_variables: usize,
+    clauses: Vec<Vec<i64>>
+}
+
+impl DimacsEncoding {
+
+    /// Creates the encoding of the framework for the semantics with the provided required arguments.
+    pub fn new(framework: &FrameworkBase, semantics: &Semantics, required_arguments: &[(usize, bool)]) -> DimacsEncoding {
+        Self::encode(framework, semantics, required_arguments, &|_| false)
+    }
+
+    /// Creates the encoding of the instance, which does not contain removed attacks, but the clauses of the extension constraint.
+    pub fn from_instance(instance: &InstanceBase, semantics: &Semantics) -> DimacsEncoding {
+        let mut encoding = Self::encode(&instance.framework, semantics, &instance.required_arguments, &|index| instance.is_attack_removed(index));
+        for index in instance.proof_start..instance.proof_start + instance.constraint_clauses {
+            let clause = encoding.to_clause(instance.get_clause(index).get_members());
+            encoding.clauses.push(clause);
+        }
+        encoding
+    }
+
+    fn encode(framework: &FrameworkBase, semantics: &Semantics, required_arguments: &[(usize, bool)], is_removed: &dyn Fn(usize) -> bool) -> DimacsEncoding {
+        let number_of_auxiliary_variables = match semantics {
+            Semantics::ConflictFree => 0,
+            Semantics::Admissible | Semantics::Stable => framework.attacks.len()
+        };
+        let mut encoding = DimacsEncoding { number_of_arguments: framework.arguments.len(), number_of_auxiliary_variables, clauses: Vec::new() };
+        let attacks : Vec<usize> = (0..framework.attacks.len()).filter(|attack| !is_removed(*attack)).collect();
+        let attacked_by = |argument: usize| framework.arguments[argument].get_attacked_by().iter().copied().filter(|attack| !is_removed(*attack));
+
+        for attack in &attacks {
+            let clause = encoding.to_clause(framework.attacks[*attack].get_members());
+            encoding.clauses.push(clause);
+        }
+
+        if number_of_auxiliary_variables > 0 {
+            //The auxiliary variable of an attack implies each of its attackers
+            for attack in &attacks {
+                for (attacker, _) in &framework.attacks[*attack].get_members()[1..] {
+                    let clause = vec![-encoding.get_attack_literal(*attack), *attacker as i64 + 1];
+                    encoding.clauses.push(clause);
+                }
+            }
+        }
+
+        match semantics {
+            Semantics::ConflictFree => {},
+            Semantics::Admissible => {
+                //A contained argument is defended against every attack, i.e. one of the attackers of the attack is attacked
+                for argument in 0..framework.arguments.len() {
+                    for attack in attacked_by(argument) {
+                        let mut defenses : Vec<i64> = framework.attacks[attack].get_members()[1..].iter()
+                            .flat_map(|(attacker, _)| attacked_by(*attacker))
+                            .map(|defense| encoding.get_attack_literal(defense))
+                            .collect();
+                        defenses.sort_unstable();
+                        defenses.dedup();
+                        let clause = [-(argument as i64 + 1)].into_iter().chain(defenses).collect();
+                        encoding.clauses.push(clause);
+                    }
+                }
+            },
+            Semantics::Stable => {
+                //An argument is contained or attacked
+                for argument in 0..framework.arguments.len() {
+                    let clause = [argument as i64 + 1].into_iter().chain(attacked_by(argument).map(|attack| encoding.get_attack_literal(attack))).collect();
+                    encoding.clauses.push(clause);
+                }
+            }
+        }
+
+        for required_argument in required_arguments {
+            let clause = vec![encoding.to_literal(*required_argument)];
+            encoding.clauses.push(clause);
+        }
+        encoding
+    }
+
+    /// Returns the DIMACS literal of a member of a clause of the instance.
+    #[inline]
+    pub fn to_literal(&self, (argument, sign): (usize, bool)) -> i64 {
+        let variable = if argument < self.number_of_arguments { argument + 1 } else { argument + 1 + self.number_of_auxiliary_variables } as i64;
+        if sign { variable } else { -variable }
+    }
+
+    /// Returns the DIMACS literals of the members of a clause of the instance.
+    pub fn to_clause(&self, members: &[(usize, bool)]) -> Vec<i64> {
+        members.iter().map(|member| self.to_literal(*member)).collect()
+    }
+
+    /// Returns the positive literal of the auxiliary variable of the attack with the provided index.
+    #[inline]
+    fn get_attack_literal(&self, attack: usize) -> i64 {
+        (self.number_of_arguments + attack + 1) as i64
+    }
+
+    #[inline]
+    pub fn get_clauses(&self) -> &Vec<Vec<i64>> {
+        &self.clauses
+    }
+
+    /// Returns the number of variables that occur in the encoding, including the auxiliary variables.
+    pub fn get_number_of_variables(&self) -> usize {
+        let largest = self.clauses.iter().flatten().map(|literal| literal.unsigned_abs() as usize).max().unwrap_or(0);
+        largest.max(self.number_of_arguments + self.number_of_auxiliary_variables)
+    }
+
+    /// Returns the index of the first proof clause for which include returns true that has a witness, as clauses that are redundant by propagation or substitution cannot be expressed in a DRAT proof.
+    pub fn find_clause_with_witness(instance: &InstanceBase, include: &dyn Fn(usize) -> bool) -> Option<usize> {
+        (instance.proof_start..instance.get_number_of_clauses()).find(|index| include(*index) && instance.get_clause(*index).get_witness().is_some())
+    }
+
+    /// Writes the proof of the instance as DRAT proof for the encoding of the instance, including only the proof clauses for which include returns true, which must not have a witness.
+    /// Implicit clauses are written like any other clause, as they are implied by reverse unit propagation under the encoding: assigning their negated members falsifies the auxiliary variables of the attacks that contain a member, and thus the clause of the semantics that the verifier found these attacks for.
+    /// Deletions of included proof clauses and of clauses of the encoding are kept before the next included clause, unless unit deletions are ignored. The proof is terminated by the empty clause.
+    pub fn write_drat(&self, instance: &InstanceBase, writer: &mut dyn Write, include: &dyn Fn(usize) -> bool) -> io::Result<()> {
+        let lemma_start = instance.proof_start + instance.constraint_clauses;
+        let mut deletions : HashMap<usize, Vec<usize>> = HashMap::new();
+        for (index, state) in instance.clause_states.iter().enumerate() {
+            if let Some(deleted_at) = state.deleted_at() {
+                let ignored = instance.deletion_policy == DeletionPolicy::IgnoreUnits && instance.get_clause(index).get_number_of_members() == 1;
+                if (index < lemma_start || include(index)) && !ignored && !instance.is_attack_removed(index) {
+                    deletions.entry(deleted_at).or_default().push(index);
+                }
+            }
+        }
+
+        let mut pending_deletions = Vec::new();
+        for index in instance.proof_start..=instance.get_number_of_clauses() {
+            if let Some(deleted) = deletions.get(&index) {
+                pending_deletions.extend(deleted);
+            }
+
+            if index < instance.get_number_of_clauses() && (index < lemma_start || !include(index)) {
+                continue;
+            }
+
+            for deleted in pending_deletions.drain(..) {
+                write!(writer, "d ")?;
+                self.write_clause(writer, &self.to_clause(instance.get_clause(deleted).get_members()))?;
+            }
+            if index < instance.get_number_of_clauses() {
+                self.write_clause(writer, &self.to_clause(instance.get_clause(index).get_members()))?;
+            }
+        }
+        writeln!(writer, "0")
+    }
+
+    /// Writes a clause as line of literals terminated by 0.
+    fn write_clause(&self, writer: &mut dyn Write, clause: &[i64]) -> io::Result<()> {
+        for literal in clause {
+            write!(writer, "{} ", literal)?;
+        }
+        writeln!(writer, "0")
+    }
+
+    /// Writes the encoding as DIMACS CNF, whose header comments document the mapping of the variables.
+    pub fn write(&self, writer: &mut dyn Write) -> io::Result<()> {
+        writeln!(writer, "c The variables 1 to {} are the arguments.", self.number_of_arguments)?;
+        if self.number_of_auxiliary_variables > 0 {
+            writeln!(writer, "c The variable {} + k implies that all attackers of the k-th attack are contained.", self.number_of_arguments)?;
+        }
+        writeln!(writer, "p cnf {} {}", self.get_number_of_variables(), self.clauses.len())?;
+        for clause in &self.clauses {
+            self.write_clause(writer, clause)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use crate::framework_base::FrameworkBase;
+use crate::instance_base::InstanceBase;
+use crate::parsers::file_reader::{FileReader, Source};
+use crate::parsers::parse_error::ParseError;
+use crate::should_stop;
+
+/// Parses the file of the listed extensions, in which every line contains the arguments of one extension followed by 0, e.g. '1 4 0', or only 0 for the empty extension.
+/// Arguments are given like the members of proof clauses, i.e. by their numbers or names. Returns the indices of the arguments of every extension, which need to be unique like the extensions.
+pub fn parse_extensions(source: &Source, framework: &FrameworkBase, strict: bool) -> Result<Vec<Vec<usize>>, ParseError> {
+    let reader = FileReader::open(source, strict)?;
+    let file = reader.get_name().to_string();
+    let tokenizer = reader.get_tokenizer();
+
+    let mut extensions : Vec<Vec<usize>> = Vec::new();
+    reader.for_each_line(|line_number, line| {
+
+        should_stop()?;
+
+        let error = |token: Option<&str>, message: String| match token {
+            Some(token) => ParseError::at_token(&file, line_number, line, token, message),
+            None => ParseError::at(&file, line_number, line, None, message)
+        };
+
+        let split = tokenizer.tokenize_quoted_reusing(line, Vec::new());
+        if split.last() != Some(&"0") {
+            return Err(error(split.last().copied(), format!("The extension line '{}' is malformed", line)));
+        }
+
+        let mut extension = Vec::with_capacity(split.len() - 1);
+        for token in &split[..split.len() - 1] {
+            let argument = match InstanceBase::parse_proof_member(token, line, framework, framework.arguments.len()).map_err(|message| error(Some(token), message))? {
+                (argument, true) => argument,
+                (_, false) => return Err(error(Some(token), format!("The extension line '{}' contains the negated argument '{}'", line, token)))
+            };
+            if extension.contains(&argument) {
+                return Err(error(Some(token), format!("The extension line '{}' contains the argument '{}' more than once", line, token)));
+            }
+            extension.push(argument);
+        }
+
+        extension.sort_unstable();
+        if extensions.contains(&extension) {
+            return Err(error(None, format!("The extension line '{}' lists an extension that has already been listed", line)));
+        }
+        extensions.push(extension);
+        Ok(())
+    })?;
+    Ok(extensions)
+}
+
+/// Returns the blocking clause of the provided extension, which excludes exactly this set of arguments, i.e. contains its arguments negated and all other arguments.
+pub fn encode_blocking(number_of_arguments: usize, extension: &[usize]) -> Vec<(usize, bool)> {
+    let mut clause : Vec<(usize, bool)> = (0..number_of_arguments).map(|argument| (argument, true)).collect();
+    for argument in extension {
+        clause[*argument].1 = false;
+    }
+    clause
+}
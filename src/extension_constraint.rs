@@ -0,0 +1,22 @@
+use crate::enumeration::encode_blocking;
+use crate::optimality::encode_at_least;
+
+///A constraint on the extensions, whose clauses are generated and trusted by the verifier and precede the proof clauses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExtensionConstraint {
+    ///Extensions need to contain at least the provided number of arguments, which is encoded by a cardinality constraint.
+    MinimumSize(usize),
+    ///Extensions must not be one of the provided sets of arguments, which are excluded by one blocking clause each.
+    Blocked(Vec<Vec<usize>>)
+}
+
+impl ExtensionConstraint {
+
+    ///Returns the clauses that encode the constraint for a framework with the provided number of arguments.
+    pub fn encode(&self, number_of_arguments: usize) -> Vec<Vec<(usize, bool)>> {
+        match self {
+            ExtensionConstraint::MinimumSize(minimum) => encode_at_least(number_of_arguments, *minimum),
+            ExtensionConstraint::Blocked(extensions) => extensions.iter().map(|extension| encode_blocking(number_of_arguments, extension)).collect()
+        }
+    }
+}
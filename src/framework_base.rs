@@ -0,0 +1,196 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::io::Write;
+use crate::argument_base::ArgumentBase;
+use crate::clause_base::ClauseBase;
+use crate::parsers::{apx, ccl, iccma, tgf};
+use crate::parsers::file_reader::{FileReader, Source};
+use crate::parsers::format::FrameworkFormat;
+use crate::parsers::number::parse_usize;
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tokenizer::Tokenizer;
+use crate::should_stop;
+
+/// Represents a framework, i.e. its arguments and attacks. Once parsed, it can be shared by the instances of multiple proofs.
+pub struct FrameworkBase {
+
+    /// The arguments of the framework.
+    pub arguments: Vec<ArgumentBase>,
+
+    /// The attacks of the framework.
+    pub attacks: Vec<ClauseBase>,
+
+    /// A mapping from argument names to indices or None if the name occurs multiple times.
+    pub argument_names: HashMap<String, Option<usize>>,
+
+    /// The indices of the attacks that are unit clauses.
+    pub unit_attacks: Vec<usize>
+}
+
+impl FrameworkBase {
+
+    /// Reads the framework and description files and creates the arguments and attacks. Unless provided, the format of the framework is detected, see FrameworkFormat::detect. Unless strict, any whitespace is accepted between tokens.
+    pub fn new(framework: &Source, format: Option<FrameworkFormat>, description: &Option<Source>, strict: bool) -> Result<FrameworkBase, ParseError> {
+
+        let format = match format {
+            Some(format) => format,
+            None => FrameworkFormat::detect(framework, strict)?
+        };
+        let instance_reader = FileReader::open(framework, strict)?;
+        let mut builder = match format {
+            FrameworkFormat::Ccl => ccl::parse(instance_reader)?,
+            FrameworkFormat::Iccma => iccma::parse(instance_reader)?,
+            FrameworkFormat::Apx => apx::parse(instance_reader)?,
+            FrameworkFormat::Tgf => tgf::parse(instance_reader.keep_comments())?
+        };
+        let num_arguments = builder.get_number_of_arguments();
+
+        //Read Description file
+        if let Some(description) = description
+        {
+            let instance_reader = FileReader::open(description, strict)?;
+            let file = instance_reader.get_name().to_string();
+            let tokenizer = instance_reader.get_tokenizer();
+
+            let mut tokens = Vec::new();
+            instance_reader.for_each_line(|line_number, line| {
+
+                should_stop()?;
+
+                let split = tokenizer.tokenize_reusing(line, std::mem::take(&mut tokens));
+
+                if split.len() < 2 {
+                    return Err(ParseError::at(&file, line_number, line, None, format!("The description line '{}' is malformed", line)))
+                }
+                let description_argument_number = match parse_usize(split[0]) {
+                    Some(description_argument_number) if description_argument_number != 0 && description_argument_number <= num_arguments => description_argument_number,
+                    _ => return Err(ParseError::at_token(&file, line_number, line, split[0], format!("The description line '{}' references an invalid argument '{}'", line, split[0])))
+                };
+                let description_argument_index: usize = description_argument_number - 1;
+
+                builder.add_name(tokenizer.get_remainder(line, split[0]), description_argument_index);
+                tokens = Tokenizer::recycle(split);
+                Ok(())
+            })?;
+        }
+
+        builder.build().ok_or_else(|| ParseError::in_file(&framework.to_string(), format!("The framework is too large, as its {} arguments cannot be allocated", num_arguments)))
+    }
+
+    /// Writes the framework in the instance file format, including only the attacks for which include returns true.
+    pub fn write(&self, writer: &mut dyn Write, include: &dyn Fn(usize) -> bool) -> io::Result<()> {
+        self.write_as(writer, FrameworkFormat::Ccl, include)
+    }
+
+    /// Writes the framework in the provided format, including only the attacks for which include returns true.
+    pub fn write_as(&self, writer: &mut dyn Write, format: FrameworkFormat, include: &dyn Fn(usize) -> bool) -> io::Result<()> {
+        let attacks : Vec<&ClauseBase> = self.attacks.iter().filter(|attack| include(attack.get_index())).collect();
+        match format {
+            FrameworkFormat::Ccl => ccl::write(self, &attacks, writer),
+            FrameworkFormat::Iccma => iccma::write(self, &attacks, writer),
+            FrameworkFormat::Apx => apx::write(self, &attacks, writer),
+            FrameworkFormat::Tgf => tgf::write(self, &attacks, writer)
+        }
+    }
+
+    /// Returns a name for every argument that has a unique one. If an argument has multiple names, the smallest one is used.
+    pub fn get_unique_names(&self) -> Vec<Option<&str>> {
+        let mut names : Vec<Option<&str>> = vec![None; self.arguments.len()];
+        for (name, index) in &self.argument_names {
+            if let Some(index) = index {
+                let entry = &mut names[*index];
+                if entry.is_none_or(|current| name.as_str() < current) {
+                    *entry = Some(name);
+                }
+            }
+        }
+        names
+    }
+}
+
+/// The maximal number of arguments per byte of the content of a framework whose number of arguments is declared, see FrameworkBuilder::fits_content.
+const MAX_ARGUMENTS_PER_BYTE: usize = 8;
+
+/// Collects the attacks and argument names of a framework while it is parsed. The arguments are only allocated once the framework is built.
+pub(crate) struct FrameworkBuilder {
+    number_of_arguments: usize,
+    attacks: Vec<ClauseBase>,
+    argument_names: HashMap<String, Option<usize>>,
+    unit_attacks: Vec<usize>,
+    /// Used to make sure that every attacker is only contained once in an attack
+    attackers: HashSet<usize>
+}
+
+impl FrameworkBuilder {
+
+    /// Creates a builder for the provided number of arguments.
+    pub(crate) fn new(number_of_arguments: usize) -> FrameworkBuilder {
+        FrameworkBuilder { number_of_arguments, attacks: Vec::new(), argument_names: HashMap::new(), unit_attacks: Vec::new(), attackers: HashSet::new() }
+    }
+
+    #[inline]
+    pub(crate) fn get_number_of_arguments(&self) -> usize {
+        self.number_of_arguments
+    }
+
+    #[inline]
+    pub(crate) fn get_number_of_attacks(&self) -> usize {
+        self.attacks.len()
+    }
+
+    /// Returns whether or not the number of arguments is plausible for a framework, whose content has the provided length in bytes.
+    /// Arguments that neither attack nor are attacked take no space in formats that declare the number of arguments, thus a small file could otherwise make the verifier allocate unbounded memory.
+    pub(crate) fn fits_content(&self, content_length: usize) -> bool {
+        self.number_of_arguments <= content_length.saturating_mul(MAX_ARGUMENTS_PER_BYTE)
+    }
+
+    /// Adds an attack on the attacked argument by the attackers, which must be valid indices. Attackers that occur multiple times are added once.
+    pub(crate) fn add_attack(&mut self, attacked: usize, attackers: impl IntoIterator<Item = usize>) {
+        let mut att = ClauseBase::new(self.attacks.len());
+        att.add_member(attacked, false);
+
+        self.attackers.clear();
+        for attacker in attackers {
+            if self.attackers.insert(attacker) {
+                att.add_member(attacker, false);
+            }
+        }
+
+        if att.get_number_of_members() == 1 {
+            self.unit_attacks.push(att.get_index());
+        }
+        self.attacks.push(att);
+    }
+
+    /// Adds a name for the argument with the provided index. Names that are used for multiple arguments are marked as not unique.
+    pub(crate) fn add_name(&mut self, name: &str, index: usize) {
+        if let Some(entry) = self.argument_names.get_mut(name) {
+            if *entry != Some(index) {
+                *entry = None;
+            }
+        }
+        else {
+            //We have not seen an argument with that name
+            self.argument_names.insert(name.to_string(), Some(index));
+        }
+    }
+
+    /// Creates the framework or returns None if its arguments cannot be allocated.
+    pub(crate) fn build(self) -> Option<FrameworkBase> {
+
+        //We report an error instead of aborting if the number of arguments is too large
+        let mut arguments = Vec::new();
+        arguments.try_reserve_exact(self.number_of_arguments).ok()?;
+        arguments.resize(self.number_of_arguments, ArgumentBase::new());
+
+        //Set the indices of the arguments and their attacks
+        for (index, argument) in arguments.iter_mut().enumerate() {
+            argument.set_id(index);
+        }
+        for attack in &self.attacks {
+            arguments[attack.get_member(0).0].add_attacked_by(attack.get_index());
+        }
+
+        Some(FrameworkBase { arguments, attacks: self.attacks, argument_names: self.argument_names, unit_attacks: self.unit_attacks })
+    }
+}
@@ -1,243 +1,304 @@
 use std::collections::HashMap;
-use std::path::PathBuf;
-use crate::argument_base::ArgumentBase;
-use crate::clause_base::ClauseBase;
-use crate::parsers::file_reader::FileReader;
-use crate::semantics::Semantics;
-use crate::should_stop;
+use std::io;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use once_cell::sync::OnceCell;
+use crate::clause_base::{ClauseBase, ClauseState};
+use crate::deletion_policy::DeletionPolicy;
+use crate::framework_base::FrameworkBase;
+use crate::parsers::file_reader::{FileReader, Source};
+use crate::parsers::number::{parse_isize, parse_u64, parse_usize};
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tokenizer::Tokenizer;
+use crate::semantics::{Semantics, VerifierType};
+use crate::witness::Witness;
+use crate::{should_stop, VerificationJob};
 
 /// Represents the instance, i.e. framework and proof, to verify.
 pub struct InstanceBase {
 
-    /// The arguments of the instance.
-    pub arguments: Vec<ArgumentBase>,
+    /// The framework of the instance, which may be shared with other instances.
+    pub framework: Arc<FrameworkBase>,
 
     /// Which arguments have been required with the respective sign.
     pub required_arguments: Vec<(usize, bool)>,
 
-    /// The attacks of the instance.
-    pub clauses: Vec<ClauseBase>,
+    /// Whether or not the required arguments, in the same order, have been used during verification.
+    required_arguments_used: Vec<AtomicBool>,
+
+    /// The clauses of the proof.
+    pub proof_clauses: Vec<ClauseBase>,
+
+    /// The states of the attacks followed by those of the proof clauses.
+    pub clause_states: Vec<ClauseState>,
 
     /// The indices of the unit clauses of the instance.
     pub unit_clauses: Vec<usize>,
 
     /// The id of the first clause that is part of the proof.
-    pub proof_start: usize
-}
-
-impl InstanceBase {
+    pub proof_start: usize,
 
-    /// Creates a new instance based on input data.
-    pub fn new<'a>(framework_path: &PathBuf, description_path: &Option<PathBuf>, required_arguments_path: &Option<PathBuf>, proof_path: &PathBuf, semantics: &Semantics) -> Result<InstanceBase, String> {
+    /// The number of clauses of the extension constraint, which are generated by the verifier and precede the clauses of the proof.
+    pub constraint_clauses: usize,
 
-        //Read the framework
-        let (arguments, mut clauses, argument_names, mut unit_clauses) = Self::parse_framework(framework_path, description_path)?;
+    /// The IDs of the proof clauses if the proof is ID-annotated.
+    pub clause_ids: Option<Vec<u64>>,
 
-        let number_of_attacks = clauses.len();
+    /// How the clause deletions of the proof are handled during verification.
+    pub deletion_policy: DeletionPolicy,
 
-        //Read required arguments
-        let required_arguments = match required_arguments_path {
-            Some(path) => Self::parse_required(path, arguments.len(), argument_names)?,
-            None => Vec::new()
-        };
+    /// Whether or not proof clauses may be verified as resolution asymmetric tautologies, which depends on the semantics.
+    pub allows_rat: bool,
 
-        //Read proof
-        match Self::parse_proof(proof_path, semantics, &arguments, &mut clauses, &mut unit_clauses) {
-            None => Ok(InstanceBase {arguments, required_arguments, clauses, unit_clauses, proof_start: number_of_attacks }),
-            Some(message) => Err(message)
-        }
-    }
+    /// The number of arguments and extension variables of the proof, which are numbered after the arguments.
+    pub number_of_variables: usize,
 
-    /// Reads the framework and description files and creates the arguments and clauses.
-    fn parse_framework<'a>(framework_path: &PathBuf, description_path: &Option<PathBuf>,) -> Result<(Vec<ArgumentBase>, Vec<ClauseBase>, HashMap<String, Option<usize>>, Vec<usize>), String> {
+    /// Whether or not the attacks have been removed from the framework, such that the proof is verified as if they were not part of it. Empty if no attack has been removed.
+    removed_attacks: Vec<bool>,
 
-        let mut instance_reader = FileReader::new(framework_path)?;
+    /// The indices of the clauses that contain a member, indexed by the argument and sign of the member. Only created once needed.
+    occurrences: OnceCell<Vec<Vec<usize>>>
+}
 
-        //Read preamble
-        let preamble = instance_reader.next();
-        if let None = preamble {
-            return Err(format!("The supplied instance contains no preamble"));
-        }
-        else if let Some(Err(message)) = preamble {
-            return Err(format!("An error occurred while reading the preamble: {}", message));
-        }
-        let preamble = preamble.unwrap().unwrap();
-        let split: Vec<&str> = preamble.split(" ").collect();
-        if split.len() != 3 || split[2] != "0" {
-            return Err(format!("Preamble is malformed: {}", preamble));
-        }
+impl InstanceBase {
 
-        //Parse number of arguments
-        let num_arguments = split[0].parse();
-        if let Err(_) = num_arguments {
-            return Err(format!("The number of arguments in the preamble is invalid: {}", split[0]));
-        }
-        let num_arguments :usize = num_arguments.unwrap();
+    /// Creates a new instance for the provided framework based on input data, which honours all deletions.
+    /// Unless strict, any whitespace is accepted between tokens. If clause_ids, every clause line of the proof starts with an ID, which deletions refer to.
+    pub fn new(framework: Arc<FrameworkBase>, required_arguments: &Option<Source>, proof: &Source, semantics: &Semantics, strict: bool, clause_ids: bool) -> Result<InstanceBase, ParseError> {
+        Self::with_constraint(framework, required_arguments, proof, semantics, strict, clause_ids, Vec::new())
+    }
 
-        //Parse number of attacks
-        let num_attacks = split[1].parse();
-        if let Err(_) = num_attacks {
-            return Err(format!("The number of attacks in the preamble is invalid: {}", split[1]));
-        }
+    /// Creates a new instance like new, in which the provided clauses of an extension constraint precede the proof clauses. They are trusted and thus not verified.
+    /// If clause_ids, their IDs follow those of the attacks.
+    fn with_constraint(framework: Arc<FrameworkBase>, required_arguments: &Option<Source>, proof: &Source, semantics: &Semantics, strict: bool, clause_ids: bool, constraint: Vec<Vec<(usize, bool)>>) -> Result<InstanceBase, ParseError> {
 
-        let num_attacks :usize = num_attacks.unwrap();
+        let number_of_attacks = framework.attacks.len();
+        let mut unit_clauses = framework.unit_attacks.clone();
+        let mut clause_states : Vec<ClauseState> = framework.attacks.iter().map(|_| ClauseState::new()).collect();
+        let mut proof_clauses = Vec::new();
+        let mut clause_ids = if clause_ids { Some(Vec::new()) } else { None };
 
-        let mut arguments= vec![ArgumentBase::new(); num_arguments];
-        let mut attacks= Vec::with_capacity(num_attacks);
-        let mut unit_clauses = Vec::new();
-        for id in 0 .. num_attacks {
-            attacks.push(ClauseBase::new(id))
-        }
+        //Read required arguments
+        let required_arguments = match required_arguments {
+            Some(source) => Self::parse_required(source, framework.arguments.len(), &framework.argument_names, strict)?,
+            None => Vec::new()
+        };
 
-        //Set the indices of the arguments
-        for (index, argument) in arguments.iter_mut().enumerate() {
-            argument.set_id(index);
+        //Add the clauses of the extension constraint
+        let constraint_clauses = constraint.len();
+        for members in constraint {
+            let mut clause = ClauseBase::new(number_of_attacks + proof_clauses.len());
+            for (argument, sign) in members {
+                clause.add_member(argument, sign);
+            }
+            clause.set_verifier(VerifierType::Assumption);
+            if let Some(clause_ids) = clause_ids.as_mut() {
+                clause_ids.push(clause.get_index() as u64 + 1);
+            }
+            if clause.get_number_of_members() == 1 {
+                unit_clauses.push(clause.get_index());
+            }
+            proof_clauses.push(clause);
+            clause_states.push(ClauseState::new());
         }
 
-        //Read the attacks
-        let mut argument_occurrence_watch = vec![0 as usize; num_arguments]; //Used to make sure that every argument is only contained once in every clause
-        let mut attacks_iter = attacks.iter_mut();
-        for line in instance_reader {
-
-            should_stop()?;
+        //Read proof
+        let number_of_variables = Self::parse_proof(FileReader::open(proof, strict), semantics, &framework, &mut proof_clauses, &mut clause_states, &mut unit_clauses, &mut clause_ids)?;
+        let required_arguments_used = required_arguments.iter().map(|_| AtomicBool::new(false)).collect();
+        Ok(InstanceBase { framework, required_arguments, required_arguments_used, proof_clauses, clause_states, unit_clauses, proof_start: number_of_attacks, constraint_clauses, clause_ids, deletion_policy: DeletionPolicy::HonourAll, allows_rat: semantics.allows_rat(), number_of_variables, removed_attacks: Vec::new(), occurrences: OnceCell::new() })
+    }
 
-            //Check that reading succeeded
-            if let Err(message) = line {
-                return Err(format!("An error occurred while reading the instance: {}", message))
-            }
+    /// Creates the instance of a job, which uses the deletion policy of the job. The framework is parsed unless provided.
+    /// If the job has an extension constraint, its clauses are added before the proof clauses.
+    pub fn from_job(job: &VerificationJob, framework: Option<Arc<FrameworkBase>>) -> Result<InstanceBase, ParseError> {
+        let framework = match framework {
+            Some(framework) => framework,
+            None => Arc::new(FrameworkBase::new(&job.framework, job.format, &job.description, job.strict)?)
+        };
+        let constraint = match &job.extension_constraint {
+            Some(constraint) => constraint.encode(framework.arguments.len()),
+            None => Vec::new()
+        };
+        let instance = Self::with_constraint(framework, &job.required_arguments, &job.proof, &job.semantics, job.strict, job.clause_ids, constraint)?;
+        Ok(InstanceBase { deletion_policy: job.deletion_policy, ..instance })
+    }
 
-            //Check that we dont exceed the specified number of attacks
-            let att = attacks_iter.next();
-            if let None = att {
-                return Err(format!("Instance contains more attacks than specified in the preamble"))
+    /// Returns a copy of the instance, in which the attacks with the provided indices are removed, such that the proof is verified for the framework without them.
+    /// Unlike deleted attacks, removed attacks are neither used for propagation nor by the implicit clauses. The clause states of the copy are reset, except for the deletions of the proof.
+    pub fn without_attacks(&self, removed_attacks: &[usize]) -> InstanceBase {
+        let mut removed = vec![false; self.proof_start];
+        for attack in removed_attacks {
+            removed[*attack] = true;
+        }
+        let clause_states = self.clause_states.iter().map(|state| {
+            let mut copy = ClauseState::new();
+            if let Some(deleted_at) = state.deleted_at() {
+                copy.set_deleted_at(deleted_at);
             }
+            copy
+        }).collect();
+
+        InstanceBase {
+            framework: Arc::clone(&self.framework),
+            required_arguments: self.required_arguments.clone(),
+            required_arguments_used: self.required_arguments.iter().map(|_| AtomicBool::new(false)).collect(),
+            proof_clauses: self.proof_clauses.clone(),
+            clause_states,
+            unit_clauses: self.unit_clauses.clone(),
+            proof_start: self.proof_start,
+            constraint_clauses: self.constraint_clauses,
+            clause_ids: self.clause_ids.clone(),
+            deletion_policy: self.deletion_policy,
+            allows_rat: self.allows_rat,
+            number_of_variables: self.number_of_variables,
+            removed_attacks: removed,
+            occurrences: OnceCell::new()
+        }
+    }
 
-            let att = att.unwrap();
+    /// Returns whether or not the attack or proof clause with the provided index is an attack that has been removed.
+    #[inline]
+    pub fn is_attack_removed(&self, index: usize) -> bool {
+        self.removed_attacks.get(index).copied().unwrap_or(false)
+    }
 
-            let line = line.unwrap();
-            let split : Vec<&str> = line.split(" ").collect();
+    /// Returns the indices of the attacks that have been used during verification.
+    pub fn get_used_attacks(&self) -> Vec<usize> {
+        (0..self.proof_start).filter(|index| self.clause_states[*index].is_used()).collect()
+    }
 
-            if split.len() < 3 || *split.last().unwrap() != "0" { //We need at least the attacked argument, at least one attacker and the trailing 0
-                return Err(format!("The attack '{}' is malformed", line))
+    /// Writes the proof in the proof file format, including only the proof clauses for which include returns true.
+    /// Deletions of included attacks and proof clauses are kept before the next included clause. The proof is terminated by the empty clause.
+    /// If the proof is ID-annotated, the clause lines start with their IDs and deletions refer to IDs.
+    pub fn write_proof(&self, writer: &mut dyn Write, include: &dyn Fn(usize) -> bool) -> io::Result<()> {
+        let mut deletions : HashMap<usize, Vec<usize>> = HashMap::new();
+        for (index, state) in self.clause_states.iter().enumerate() {
+            if let Some(deleted_at) = state.deleted_at() {
+                if include(index) {
+                    deletions.entry(deleted_at).or_default().push(index);
+                }
             }
-            let mut split = split.into_iter();
+        }
 
-            //Read the attacked argument
-            let attacked_arg_str = split.next().unwrap();
-            let attacked_arg_number = attacked_arg_str.parse();
-            if let Err(_) = attacked_arg_number {
-                return Err(format!("The attacked argument index '{}' is invalid in line '{}'", attacked_arg_str, line));
-            }
-            let attacked_arg_number: usize = attacked_arg_number.unwrap();
-            if attacked_arg_number == 0 || attacked_arg_number > num_arguments {
-                return Err(format!("The attacked argument index '{}' is invalid in line '{}'", attacked_arg_str, line));
+        let mut pending_deletions = Vec::new();
+        for index in self.proof_start..=self.get_number_of_clauses() {
+            if let Some(deleted) = deletions.get(&index) {
+                pending_deletions.extend(deleted);
             }
-            let attacked_arg_index = attacked_arg_number - 1;
-            att.add_member(attacked_arg_index, false);
-            arguments[attacked_arg_index].add_attacked_by(att.get_index());
-
-            //Read the remaining attack members
-            for attack_member_str in split {
-                //Parse the attack member
-                let attack_member_number = attack_member_str.parse();
-                if let Err(_) = attack_member_number {
-                    return Err(format!("The attack '{}' contains an invalid attack member '{}'", line, attack_member_str));
-                }
-                let attack_member_number: usize = attack_member_number.unwrap();
 
-                //If 0 we assume we have reached the end and exit the loop
-                if attack_member_number == 0 {
-                    break;
-                }
+            if index < self.get_number_of_clauses() && !include(index) {
+                continue;
+            }
 
-                if attack_member_number > num_arguments {
-                    return Err(format!("The attack '{}' refers to an invalid attack member '{}'", line, attack_member_str));
-                }
-                let attack_member_index :usize = attack_member_number - 1;
-                let arg_occurrence = argument_occurrence_watch.get_mut(attack_member_index).unwrap();
-                if *arg_occurrence < att.get_index() + 1 {
-                    att.add_member(attack_member_index, false);
-                    *arg_occurrence = att.get_index() + 1;
+            for deleted in pending_deletions.drain(..) {
+                match self.get_clause_id(deleted) {
+                    Some(id) => writeln!(writer, "d {} 0", id)?,
+                    None => writeln!(writer, "d {}", self.get_clause(deleted).to_members_line())?
                 }
             }
-
-            if att.get_number_of_members() == 1 {
-                unit_clauses.push(att.get_index());
+            if index < self.get_number_of_clauses() {
+                match self.get_clause_id(index) {
+                    Some(id) => writeln!(writer, "{} {}", id, self.get_clause(index).to_proof_line())?,
+                    None => writeln!(writer, "{}", self.get_clause(index).to_proof_line())?
+                }
             }
         }
+        writeln!(writer, "0")
+    }
 
-        //Check that there are no attacks left after reading the instance
-        if attacks_iter.next().is_some() {
-            return Err(format!("Instance contains more attacks than specified in the preamble"))
+    /// Returns the attack or proof clause with the provided index.
+    #[inline]
+    pub fn get_clause(&self, index: usize) -> &ClauseBase {
+        if index < self.proof_start {
+            &self.framework.attacks[index]
         }
+        else {
+            &self.proof_clauses[index - self.proof_start]
+        }
+    }
 
-        //Read Description file
-        let mut argument_index_to_names_map: HashMap<String, Option<usize>> = HashMap::new(); //A mapping from names to indices or None is the name occurs multiple time
-        if let Some(description_path) = description_path
-        {
-            let instance_reader = match FileReader::new(&description_path) {
-                Ok(instance) => instance,
-                Err(message) => return Err(message)
-            };
-
-            for line in instance_reader {
+    /// Returns the ID of the attack or proof clause with the provided index if the proof is ID-annotated. The IDs of the attacks are their 1-based indices.
+    pub fn get_clause_id(&self, index: usize) -> Option<u64> {
+        let clause_ids = self.clause_ids.as_ref()?;
+        if index < self.proof_start {
+            Some(index as u64 + 1)
+        }
+        else {
+            clause_ids.get(index - self.proof_start).copied()
+        }
+    }
 
-                should_stop()?;
+    /// Returns a description of the attack or proof clause with the provided index for messages, which contains its ID if the proof is ID-annotated.
+    pub fn describe_clause(&self, index: usize) -> String {
+        let description = if index < self.proof_start {
+            format!("attack with (0-based) index {}", index)
+        }
+        else if index < self.proof_start + self.constraint_clauses {
+            format!("clause of the extension constraint with (0-based) index {}", index - self.proof_start)
+        }
+        else {
+            format!("proof clause with (0-based) index {}", index - self.proof_start - self.constraint_clauses)
+        };
+        match self.get_clause_id(index) {
+            Some(id) => format!("{} (ID {})", description, id),
+            None => description
+        }
+    }
 
-                //Check that reading succeeded
-                if let Err(message) = line {
-                    return Err(format!("An error occurred while reading the description file: {}", message))
+    /// Returns the indices of the attacks and proof clauses that contain the provided member in ascending order. The occurrence lists of all members are created on the first call.
+    pub fn get_occurrences(&self, argument: usize, sign: bool) -> &Vec<usize> {
+        let occurrences = self.occurrences.get_or_init(|| {
+            let mut occurrences = vec![Vec::new(); self.number_of_variables * 2];
+            for index in 0..self.get_number_of_clauses() {
+                for (argument, sign) in self.get_clause(index).get_members() {
+                    let clauses : &mut Vec<usize> = &mut occurrences[*argument << 1 | *sign as usize];
+                    //The attacked argument of an attack may also be one of its attackers
+                    if clauses.last() != Some(&index) {
+                        clauses.push(index);
+                    }
                 }
+            }
+            occurrences
+        });
+        &occurrences[argument << 1 | sign as usize]
+    }
 
-                let line = line.unwrap();
-                let split : Vec<&str> = line.split(" ").collect();
-
-                if split.len() < 2 {
-                    return Err(format!("The description line '{}' is malformed", line))
-                }
-                let description_argument_number = split[0].parse();
-                if let Err(_) = description_argument_number {
-                    return Err(format!("The description line '{}' references an invalid argument '{}'", line, split[0]));
-                }
-                let description_argument_number: usize = description_argument_number.unwrap();
-                let description_argument_index: usize = description_argument_number - 1;
-                if description_argument_number == 0 || description_argument_number > num_arguments {
-                    return Err(format!("The description line '{}' references an invalid argument '{}'", line, split[0]));
-                }
+    /// Marks the required argument at the provided position of the required arguments as used.
+    #[inline]
+    pub fn set_required_argument_used(&self, position: usize) {
+        self.required_arguments_used[position].store(true, Ordering::Release);
+    }
 
-                let argument_name = &line[split[0].len() + 1 ..]; //Length of the number + 1 space
-                if let Some(entry) = argument_index_to_names_map.get_mut(argument_name) {
-                    *entry = None;
-                }
-                else {
-                    //We have not seen an argument with that name
-                    argument_index_to_names_map.insert(argument_name.to_string(), Some(description_argument_index));
-                }
-            }
-        }
+    /// Returns the required arguments that have been used during verification, i.e. whose values lead to a conflict found by reverse unit propagation, or as premise of a witness.
+    pub fn get_used_required_arguments(&self) -> Vec<(usize, bool)> {
+        self.required_arguments.iter().zip(&self.required_arguments_used)
+            .filter(|(_, used)| used.load(Ordering::Acquire))
+            .map(|(required_argument, _)| *required_argument)
+            .collect()
+    }
 
-        Ok((arguments, attacks, argument_index_to_names_map, unit_clauses))
+    /// Returns the number of attacks and proof clauses.
+    #[inline]
+    pub fn get_number_of_clauses(&self) -> usize {
+        self.clause_states.len()
     }
 
     /// Parses the required arguments file.
-    fn parse_required(required_arguments_path: &PathBuf, number_of_arguments: usize, argument_names: HashMap<String, Option<usize>>) -> Result<Vec<(usize, bool)>, String> {
+    pub fn parse_required(required_arguments_source: &Source, number_of_arguments: usize, argument_names: &HashMap<String, Option<usize>>, strict: bool) -> Result<Vec<(usize, bool)>, ParseError> {
         let mut required_arguments : Vec<(usize, bool)> = Vec::new();
-        let instance_reader = match FileReader::new(&required_arguments_path) {
-            Ok(instance) => instance,
-            Err(message) => return Err(message)
-        };
+        let instance_reader = FileReader::open(required_arguments_source, strict)?;
+        let file = instance_reader.get_name().to_string();
+        let tokenizer = instance_reader.get_tokenizer();
 
-        for line in instance_reader {
+        let mut tokens = Vec::new();
+        instance_reader.for_each_line(|line_number, line| {
 
             should_stop()?;
 
-            //Check that reading succeeded
-            if let Err(message) = line {
-                return Err(format!("An error occurred while reading the required arguments file: {}", message))
-            }
+            let error = |token: &str, message: String| ParseError::at_token(&file, line_number, line, token, message);
 
-            let line = line.unwrap();
-            let split : Vec<&str> = line.split(" ").collect();
+            let split = tokenizer.tokenize_reusing(line, std::mem::take(&mut tokens));
             let required_argument = match split.len() {
                 1 => //Just the argument number
                     {
@@ -247,22 +308,18 @@ impl InstanceBase {
                                 &(split[0])[1..]
                             }
                             else {
-                                &split[0]
+                                split[0]
                             };
 
-                        let argument_number = argument_string.parse();
-                        if let Err(_) = argument_number {
-                            return Err(format!("The required argument file references argument number '{}' that is invalid", argument_string));
-                        }
-                        let argument_number :usize = argument_number.unwrap();
-                        if argument_number == 0 || argument_number > number_of_arguments {
-                            return Err(format!("The required argument file references argument number '{}' that is invalid", argument_string));
-                        }
+                        let argument_number = match parse_usize(argument_string) {
+                            Some(argument_number) if argument_number != 0 && argument_number <= number_of_arguments => argument_number,
+                            _ => return Err(error(argument_string, format!("The required argument file references argument number '{}' that is invalid", argument_string)))
+                        };
                         (argument_number - 1, !is_negative)
                     },
                 _ => { //The argument name
                         if split[0] != "s" {
-                            return Err(format!("The line '{}' in the arguments file is malformed", line))
+                            return Err(error(split[0], format!("The line '{}' in the arguments file is malformed", line)))
                         }
                         let is_negative = split[1].starts_with("-");
                         let argument_string =
@@ -270,7 +327,7 @@ impl InstanceBase {
                                 &(split[1])[1..]
                             }
                             else {
-                                &split[1]
+                                split[1]
                             };
 
                         let entry = argument_names.get(argument_string);
@@ -279,141 +336,253 @@ impl InstanceBase {
                                 (*index, !is_negative)
                             }
                             else {
-                                return Err(format!("The required argument file references argument name '{}' that is not unique", argument_string));
+                                return Err(error(argument_string, format!("The required argument file references argument name '{}' that is not unique", argument_string)));
                             }
                         }
                         else {
-                            return Err(format!("The required argument file references argument name '{}' that is invalid", argument_string));
+                            return Err(error(argument_string, format!("The required argument file references argument name '{}' that is invalid", argument_string)));
                         }
                     }
             };
 
             required_arguments.push(required_argument);
-        }
+            tokens = Tokenizer::recycle(split);
+            Ok(())
+        })?;
 
         Ok(required_arguments)
     }
 
-    /// Parses the proof file and adds the respective clauses.
-    fn parse_proof(proof_path: &PathBuf, semantics: &Semantics, arguments: &Vec<ArgumentBase>, clauses: &mut Vec<ClauseBase>, unit_clauses: &mut Vec<usize>) -> Option<String> {
+    /// Parses the proof file and adds the respective clauses, whose indices start after the attacks, and their states.
+    /// Deletions refer to the set of literals of an attack or proof clause, regardless of their order. Attacks are deleted by their negated members, e.g. 'd -2 -1 0' deletes the attack '2 1 0'.
+    /// If clause_ids is provided, every clause line starts with an ID, which is added to clause_ids, and deletions refer to IDs, where the IDs 1 to the number of attacks refer to the attacks.
+    /// Numbers beyond the arguments refer to extension variables, which follow those of the clauses that have already been added, e.g. of an extension constraint. Returns the number of arguments and extension variables.
+    fn parse_proof(instance_reader: Result<FileReader, ParseError>, semantics: &Semantics, framework: &FrameworkBase, clauses: &mut Vec<ClauseBase>, clause_states: &mut Vec<ClauseState>, unit_clauses: &mut Vec<usize>, clause_ids: &mut Option<Vec<u64>>) -> Result<usize, ParseError> {
+
+        let number_of_arguments = framework.arguments.len();
+        let proof_start = framework.attacks.len();
+        let instance_reader = instance_reader?;
+        let file = instance_reader.get_name().to_string();
+        let tokenizer = instance_reader.get_tokenizer();
+
+        let number_of_variables = clauses.iter().flat_map(|clause| clause.get_members()).map(|(argument, _)| argument + 1).max().unwrap_or_default().max(number_of_arguments);
+        let mut argument_occurrence_watch = vec![0_usize; number_of_variables]; //Used to make sure that every argument is only contained once in every clause, grows with the extension variables
+        let mut literal_set_to_clauses : HashMap<u64, Vec<usize>> = HashMap::new(); //The indices of the clauses by the hash of their set of literals
+        let mut attacks_indexed = false; //The attacks are only added to literal_set_to_clauses once the first deletion is read
+        let mut id_to_index : HashMap<u64, usize> = HashMap::new(); //The indices of the proof clauses by their IDs, if the proof is ID-annotated
+
+        //Clauses that have already been added can be deleted like proof clauses
+        for (position, clause) in clauses.iter().enumerate() {
+            match clause_ids.as_ref() {
+                Some(clause_ids) => { id_to_index.insert(clause_ids[position], clause.get_index()); },
+                None => literal_set_to_clauses.entry(clause.get_literal_set_hash()).or_default().push(clause.get_index())
+            }
+        }
 
-        let number_of_arguments = arguments.len();
-        let instance_reader = match FileReader::new(proof_path) {
-            Ok(reader) => reader,
-            Err(message) => return Some(message)
-        };
+        let mut tokens = Vec::new();
+        let mut found_empty_clause = false;
+        let result = instance_reader.for_each_line(|line_number, line| {
 
-        let mut argument_occurrence_watch = vec![0 as usize; number_of_arguments]; //Used to make sure that every argument is only contained once in every clause
-        let mut clause_string_to_bases : HashMap<String, (Vec<usize>, usize)> = HashMap::new();
+            should_stop()?;
 
-        let mut iterator = instance_reader.into_iter();
-        let mut found_empty_clause = false;
-        while let Some(line) = iterator.next()
-        {
-            if let Err(message) = should_stop() {
-                return Some(message);
-            }
+            let error = |token: Option<&str>, message: String| match token {
+                Some(token) => ParseError::at_token(&file, line_number, line, token, message),
+                None => ParseError::at(&file, line_number, line, None, message)
+            };
 
-            //Check that reading succeeded
-            if let Err(message) = line {
-                return Some(format!("An error occurred while reading the proof: {}", message))
+            if found_empty_clause {
+                return Err(error(None, "The last line of the proof must be the empty clause".to_string()));
             }
 
-            let line = line.unwrap();
+            let split = tokenizer.tokenize_quoted_reusing(line, std::mem::take(&mut tokens));
+            let first_token = split.first().copied().unwrap_or_default();
 
-            if line.starts_with('d') { //Handle clause deletion
-                let cleaned_line = &line[2..];
-                let deletion_clause = match Self::parse_proof_clause(clauses.len(),&line, cleaned_line, &mut argument_occurrence_watch, number_of_arguments, true) {
-                    Ok(clause) => clause,
-                    Err(message) => return Some(message)
-                };
+            if first_token == "d" && clause_ids.is_some() { //Handle clause deletion by IDs
+                if split.len() < 2 || *split.last().unwrap() != "0" {
+                    return Err(error(split.last().copied(), format!("The proof line '{}' is malformed", line)));
+                }
+                if split.len() == 2 {
+                    return Err(error(None, format!("Clause deletion line '{}' cannot be empty", line)));
+                }
+
+                let current_clause_id = proof_start + clauses.len();
+                for id_str in &split[1..split.len() - 1] {
+                    let clause_index = match parse_u64(id_str) {
+                        Some(id) if id != 0 && id <= proof_start as u64 => Some(id as usize - 1),
+                        Some(id) => id_to_index.get(&id).copied(),
+                        None => return Err(error(Some(id_str), format!("Clause deletion line '{}' contains an invalid clause ID '{}'", line, id_str)))
+                    };
+                    match clause_index {
+                        Some(clause_index) if clause_states[clause_index].deleted_at().is_none() => clause_states[clause_index].set_deleted_at(current_clause_id),
+                        Some(_) => return Err(error(Some(id_str), format!("Clause deletion line '{}' references the clause with ID '{}' that has already been deleted.", line, id_str))),
+                        None => return Err(error(Some(id_str), format!("Clause deletion line '{}' references the clause with ID '{}' that does not exist", line, id_str)))
+                    }
+                }
+
+            } else if first_token == "d" { //Handle clause deletion
+                let (deletion_clause, _) = Self::parse_proof_clause(proof_start + clauses.len(), line, &split[1..], &mut argument_occurrence_watch, framework, true, &error)?;
 
                 if deletion_clause.get_number_of_members() == 0 {
-                    return Some(format!("Clause deletion line '{}' cannot be empty", line));
+                    return Err(error(None, format!("Clause deletion line '{}' cannot be empty", line)));
                 }
 
-                let current_clause_id = clauses.len();
+                let current_clause_id = proof_start + clauses.len();
+
+                if !attacks_indexed {
+                    for attack in &framework.attacks {
+                        literal_set_to_clauses.entry(attack.get_literal_set_hash()).or_default().push(attack.get_index());
+                    }
+                    attacks_indexed = true;
+                }
 
-                //Find the deleted clause an mark it
-                match clause_string_to_bases.get_mut(cleaned_line) {
-                    Some((clause_vec, index)) if index < &mut clause_vec.len() => {
-                        let clause_index = clause_vec.get(*index).unwrap();
-                        let clause = clauses.get_mut(*clause_index).unwrap();
-                        *index = *index + 1;
-                        clause.set_deleted_at(current_clause_id);
+                //Find the deleted clause an mark it. If several proof clauses or several attacks match, the one that was added first is deleted
+                let get_clause = |index: usize| if index < proof_start { &framework.attacks[index] } else { &clauses[index - proof_start] };
+                let mut exists = false;
+                let mut first_attack = None;
+                let mut first_proof_clause = None;
+                for clause_index in literal_set_to_clauses.get(&deletion_clause.get_literal_set_hash()).into_iter().flatten() {
+                    if !get_clause(*clause_index).has_same_literal_set(&deletion_clause) {
+                        continue;
                     }
-                    Some(_) => return Some(format!("Clause deletion line '{}' references a clause that has already been deleted.", line)),
-                    None => return Some(format!("Clause deletion line '{}' references a clause that does not exist", line))
+                    exists = true;
+                    if clause_states[*clause_index].deleted_at().is_none() {
+                        let first = if *clause_index < proof_start { &mut first_attack } else { &mut first_proof_clause };
+                        if first.is_none_or(|first| *clause_index < first) {
+                            *first = Some(*clause_index);
+                        }
+                    }
+                }
+
+                let clause_index = match (first_attack, first_proof_clause) {
+                    (Some(attack_index), Some(clause_index)) => return Err(error(None, format!("Clause deletion line '{}' is ambiguous, as it references the attack '{}' as well as the proof clause '{}'", line, framework.attacks[attack_index].to_attack_line(), get_clause(clause_index).to_proof_line()))),
+                    (Some(clause_index), None) | (None, Some(clause_index)) => clause_index,
+                    (None, None) if exists => return Err(error(None, format!("Clause deletion line '{}' references a clause that has already been deleted.", line))),
+                    (None, None) => return Err(error(None, format!("Clause deletion line '{}' references a clause that does not exist", line)))
                 };
+                clause_states[clause_index].set_deleted_at(current_clause_id);
 
             } else { //Handle other clause types
-                let verifier = semantics.get_verifier(&line);
+                //Read the ID of the clause, which is optional for the empty clause
+                let (id, split) = match clause_ids {
+                    Some(_) if split.len() > 1 || first_token != "0" => {
+                        let id = match parse_u64(first_token) {
+                            Some(id) if id != 0 => id,
+                            _ => return Err(error(Some(first_token), format!("Clause line '{}' does not start with a valid clause ID", line)))
+                        };
+                        if id <= proof_start as u64 {
+                            return Err(error(Some(first_token), format!("The clause ID '{}' is not unique, as the IDs 1 to {} refer to the attacks", first_token, proof_start)));
+                        }
+                        if id_to_index.contains_key(&id) {
+                            return Err(error(Some(first_token), format!("The clause ID '{}' is not unique", first_token)));
+                        }
+                        (Some(id), &split[1..])
+                    },
+                    _ => (None, &split[..])
+                };
+
+                //Read the permutation of a clause that is redundant by substitution
+                let (permutation, split) = match split.first().and_then(|token| token.strip_prefix("s{")).and_then(|mappings| mappings.strip_suffix('}')) {
+                    Some(mappings) => (Self::parse_permutation(framework, mappings, line).map_err(|message| error(split.first().copied(), message))?, &split[1..]),
+                    None => (Vec::new(), split)
+                };
+                let first_token = split.first().copied().unwrap_or_default();
+
+                let verifier = semantics.get_verifier(first_token);
                 if verifier.is_none() {
-                    return Some(format!("Clause line '{}' is malformed", line))
+                    return Err(error(Some(first_token), format!("Clause line '{}' is malformed", line)))
                 }
 
-                let (start_index, verifier) = verifier.unwrap();
-                let cleaned_line = &line[start_index..];
-                match Self::parse_proof_clause(clauses.len(),&line, &cleaned_line, &mut argument_occurrence_watch, number_of_arguments, false) {
-                    Ok(mut clause) => {
-                        if clause.get_number_of_members() == 0 {
-                            found_empty_clause = true;
-                            break; //Empty clause at the end of the proof
-                        }
+                let (has_prefix, verifier) = verifier.unwrap();
+                let clause_tokens = if has_prefix { &split[1..] } else { split };
+                let (mut clause, assignment) = Self::parse_proof_clause(proof_start + clauses.len(), line, clause_tokens, &mut argument_occurrence_watch, framework, false, &error)?;
+                if clause.get_number_of_members() == 0 {
+                    if !matches!(verifier, VerifierType::RUP) {
+                        return Err(error(None, format!("Clause line '{}' is malformed, as implicit clauses cannot be empty", line)));
+                    }
+                    if !permutation.is_empty() {
+                        return Err(error(None, format!("Clause line '{}' is malformed, as the empty clause cannot have a witness", line)));
+                    }
+                    found_empty_clause = true; //Empty clause at the end of the proof
+                    return Ok(());
+                }
 
-                        clause.set_verifier(verifier);
-                        clause_string_to_bases.entry(cleaned_line.to_string()).or_insert((Vec::new(), 0)).0.push(clause.get_index());
-                        if clause.get_number_of_members() == 1 {
-                            unit_clauses.push(clause.get_index());
-                        }
-                        clauses.push(clause);
+                if !matches!(verifier, VerifierType::RUP) && clause.get_member(0).0 >= number_of_arguments {
+                    return Err(error(None, format!("Clause line '{}' is malformed, as implicit clauses need to start with an argument", line)));
+                }
+
+                //Resolve an attack that is named by its attackers
+                let verifier = match semantics.get_named_attackers(first_token) {
+                    Some(attackers) => VerifierType::Admissibility(Some(Self::find_attack(framework, clause.get_member(0).0, attackers, line).map_err(|message| error(Some(first_token), message))?)),
+                    None => verifier
+                };
+
+                //Attach the witness of a clause that is redundant by propagation or substitution
+                if !assignment.is_empty() || !permutation.is_empty() {
+                    if !matches!(verifier, VerifierType::RUP) {
+                        return Err(error(None, format!("Clause line '{}' is malformed, as implicit clauses cannot have witnesses", line)));
+                    }
+                    if assignment.iter().any(|(argument, _)| *argument < number_of_arguments) && !semantics.allows_rat() {
+                        return Err(error(None, format!("The proof line '{}' contains a witness that assigns arguments, which is only supported for the semantics ConflictFree", line)));
+                    }
+                    clause.set_witness(Witness::new(assignment, permutation));
+                }
+
+                clause.set_verifier(verifier);
+                match (id, clause_ids.as_mut()) {
+                    (Some(id), Some(clause_ids)) => {
+                        id_to_index.insert(id, clause.get_index());
+                        clause_ids.push(id);
                     },
-                    Err(message) => return Some(message)
+                    _ => literal_set_to_clauses.entry(clause.get_literal_set_hash()).or_default().push(clause.get_index())
+                }
+                if clause.get_number_of_members() == 1 {
+                    unit_clauses.push(clause.get_index());
                 }
+                clauses.push(clause);
+                clause_states.push(ClauseState::new());
             }
-        }
 
+            tokens = Tokenizer::recycle(split);
+            Ok(())
+        });
+
+        result?;
         if !found_empty_clause {
-            return Some(format!("The last line of the proof must be the empty clause"));
-        }
-        else {
-            if let Some(result) = iterator.next() {
-                return if result.is_ok() {
-                    Some(format!("The last line of the proof must be the empty clause"))
-                } else {
-                    Some(format!("An error occurred while reading the end of the proof: {}", result.err().unwrap()))
-                }
-            }
+            return Err(ParseError::in_file(&file, "The last line of the proof must be the empty clause".to_string()));
         }
 
-        None
+        Ok(argument_occurrence_watch.len())
     }
 
-    /// Parses a proof clause
-    fn parse_proof_clause(clause_id: usize, complete_line: &str, cleaned_line: &str, argument_occurrence_watch: &mut Vec<usize>, number_of_arguments: usize, is_deletion_clause: bool) -> Result<ClauseBase, String> {
+    /// Parses a proof clause, whose members are argument numbers or names. Errors are created by the provided function for the current line and the offending token, if any.
+    /// Unless the clause is deleted, a second occurrence of the first member starts the witness assignment of a clause that is redundant by propagation, which is returned as well.
+    /// Unless the clause is deleted, it may introduce fresh extension variables, for which argument_occurrence_watch grows, such that its length is the number of arguments and extension variables.
+    fn parse_proof_clause(clause_id: usize, complete_line: &str, split: &[&str], argument_occurrence_watch: &mut Vec<usize>, framework: &FrameworkBase, is_deletion_clause: bool, error: &dyn Fn(Option<&str>, String) -> ParseError) -> Result<(ClauseBase, Vec<(usize, bool)>), ParseError> {
         let mut clause = ClauseBase::new(clause_id);
 
-        let split : Vec<&str> = cleaned_line.split(" ").collect();
-        if split.len() < 1 || *split.last().unwrap() != "0" { //We need at least the trailing 0
+        if split.is_empty() || *split.last().unwrap() != "0" { //We need at least the trailing 0
 
-            return Err(format!("The proof line '{}' is malformed", complete_line))
+            return Err(error(split.last().copied(), format!("The proof line '{}' is malformed", complete_line)))
         }
 
         //Read the clause members
+        let mut assignment = Vec::new();
+        let mut assigned : HashMap<usize, bool> = HashMap::new();
         for clause_member_str in split.split_last().unwrap().1 {
-            //Parse the clause member
-            let clause_member_number = clause_member_str.parse();
-            if let Err(_) = clause_member_number {
-                return Err(format!("The proof line '{}' contains an invalid argument '{}'", complete_line, clause_member_str));
+            let number_of_variables = argument_occurrence_watch.len();
+            let max_number = if is_deletion_clause { number_of_variables } else { number_of_variables + 1 };
+            let (clause_member_index, sign) = Self::parse_proof_member(clause_member_str, complete_line, framework, max_number).map_err(|message| error(Some(clause_member_str), message))?;
+            if clause_member_index == number_of_variables {
+                argument_occurrence_watch.push(0);
             }
-            let clause_member_number: isize = clause_member_number.unwrap();
-            let sign = clause_member_number.is_positive();
-            let clause_member_number= usize::try_from(clause_member_number.abs()).unwrap();
-
-            if clause_member_number > number_of_arguments {
-                return Err(format!("The clause '{}' refers to an invalid argument '{}'", complete_line, clause_member_str));
+            if !assignment.is_empty() || (!is_deletion_clause && clause.get_number_of_members() > 0 && clause.get_member(0) == (clause_member_index, sign)) {
+                match assigned.insert(clause_member_index, sign) {
+                    None => assignment.push((clause_member_index, sign)),
+                    Some(value) if value != sign => return Err(error(Some(clause_member_str), format!("The witness of the proof line '{}' is inconsistent, as it contains the argument '{}' with both signs", complete_line, clause_member_index + 1))),
+                    Some(_) => {}
+                }
+                continue;
             }
-            let clause_member_index:usize = clause_member_number - 1;
             let arg_occurrence = argument_occurrence_watch.get_mut(clause_member_index).unwrap();
             if *arg_occurrence < clause.get_index() + 1 {
                 clause.add_member(clause_member_index, sign);
@@ -428,7 +597,91 @@ impl InstanceBase {
             }
         }
 
-        Ok(clause)
+        Ok((clause, assignment))
+    }
+
+    /// Parses a clause member, i.e. an argument number or a name of the description, which is negated by a leading '-'. Names in double quotes may contain spaces and are never read as numbers.
+    /// Numbers up to max_number are accepted, where those beyond the arguments refer to extension variables. Returns the index of the argument or variable and its sign.
+    pub(crate) fn parse_proof_member(member: &str, complete_line: &str, framework: &FrameworkBase, max_number: usize) -> Result<(usize, bool), String> {
+        let (sign, argument) = match member.strip_prefix('-') {
+            Some(argument) => (false, argument),
+            None => (true, member)
+        };
+
+        let name = match argument.strip_prefix('"').and_then(|argument| argument.strip_suffix('"')) {
+            Some(name) => name,
+            None => match parse_isize(member) {
+                Some(number) => {
+                    if number.unsigned_abs() > framework.arguments.len() && number.unsigned_abs() > max_number && max_number > framework.arguments.len() {
+                        return Err(format!("The clause '{}' refers to the extension variable '{}', but extension variables need to be introduced consecutively, such that the largest valid one is '{}'", complete_line, member, max_number));
+                    }
+                    if number == 0 || number.unsigned_abs() > max_number {
+                        return Err(format!("The clause '{}' refers to an invalid argument '{}'", complete_line, member));
+                    }
+                    return Ok((number.unsigned_abs() - 1, number.is_positive()));
+                },
+                None => argument
+            }
+        };
+
+        match framework.argument_names.get(name) {
+            Some(Some(index)) => Ok((*index, sign)),
+            Some(None) => Err(format!("The proof line '{}' references argument name '{}' that is not unique", complete_line, name)),
+            None => Err(format!("The proof line '{}' contains an invalid argument '{}'", complete_line, member))
+        }
+    }
+
+    /// Parses the comma-separated mappings '<argument>:<image>' of the permutation of a clause that is redundant by substitution, whose arguments are given like clause members.
+    /// The mapped arguments need to be the images, such that the mappings form a permutation. Returns the indices of the arguments and their images.
+    fn parse_permutation(framework: &FrameworkBase, mappings: &str, complete_line: &str) -> Result<Vec<(usize, usize)>, String> {
+        let mut permutation = Vec::new();
+        let mut quoted = false;
+        for mapping in mappings.split(|c: char| { quoted ^= c == '"'; c == ',' && !quoted }) {
+            let mut quoted = false;
+            let mut parts = mapping.splitn(2, |c: char| { quoted ^= c == '"'; c == ':' && !quoted });
+            let (argument, image) = match (parts.next(), parts.next()) {
+                (Some(argument), Some(image)) => (argument.trim(), image.trim()),
+                _ => return Err(format!("The proof line '{}' contains the malformed mapping '{}'", complete_line, mapping))
+            };
+
+            let parse_argument = |argument: &str| match Self::parse_proof_member(argument, complete_line, framework, framework.arguments.len())? {
+                (index, true) => Ok(index),
+                (_, false) => Err(format!("The proof line '{}' maps the negated argument '{}'", complete_line, argument))
+            };
+            permutation.push((parse_argument(argument)?, parse_argument(image)?));
+        }
+
+        let mut arguments : Vec<usize> = permutation.iter().map(|(argument, _)| *argument).collect();
+        let mut images : Vec<usize> = permutation.iter().map(|(_, image)| *image).collect();
+        arguments.sort_unstable();
+        images.sort_unstable();
+        if arguments.windows(2).any(|pair| pair[0] == pair[1]) || arguments != images {
+            return Err(format!("The mappings of the proof line '{}' do not form a permutation of the arguments", complete_line));
+        }
+        Ok(permutation)
+    }
+
+    /// Returns the index of the attack on the provided argument whose set of attackers are the comma-separated arguments, which are given like clause members.
+    fn find_attack(framework: &FrameworkBase, attacked: usize, attackers: &str, complete_line: &str) -> Result<usize, String> {
+        let mut attacker_indices = Vec::new();
+        let mut quoted = false;
+        for attacker in attackers.split(|c: char| { quoted ^= c == '"'; c == ',' && !quoted }) {
+            match Self::parse_proof_member(attacker.trim(), complete_line, framework, framework.arguments.len())? {
+                (index, true) => attacker_indices.push(index),
+                (_, false) => return Err(format!("The proof line '{}' names an attack by the negated attacker '{}'", complete_line, attacker.trim()))
+            }
+        }
+        attacker_indices.sort_unstable();
+        attacker_indices.dedup();
+
+        for attack_index in framework.arguments[attacked].get_attacked_by() {
+            let mut members : Vec<usize> = framework.attacks[*attack_index].get_members()[1..].iter().map(|(argument, _)| *argument).collect();
+            members.sort_unstable();
+            if members == attacker_indices {
+                return Ok(*attack_index);
+            }
+        }
+        Err(format!("The proof line '{}' names an attack by the attackers '{}' that does not attack the argument '{}'", complete_line, attackers, attacked + 1))
     }
 
     pub fn is_required_arguments_consistent(&self) -> bool {
@@ -442,6 +695,6 @@ impl InstanceBase {
                 }
             }
         }
-        return true;
+        true
     }
 }
\ No newline at end of file
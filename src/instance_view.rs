@@ -1,8 +1,10 @@
-use std::collections::VecDeque;
+use std::collections::{HashSet, VecDeque};
 use crate::argument_view::ArgumentView;
 use crate::clause_view::ClauseView;
+use crate::deletion_policy::DeletionPolicy;
 use crate::instance_base::InstanceBase;
 use crate::semantics::VerifierType;
+use crate::witness::Witness;
 
 /// Represents a worker threads view of an instance, with its respective argument and clause views.
 pub struct InstanceView<'a> {
@@ -10,24 +12,30 @@ pub struct InstanceView<'a> {
     iteration: usize,
     arguments: Vec<ArgumentView>,
     clauses: Vec<ClauseView<'a>>,
-    clauses_to_check: VecDeque<usize>
+    clauses_to_check: VecDeque<usize>,
+    /// The position of every argument in the required arguments, if it is required.
+    required_positions: Vec<Option<usize>>
 }
 
 impl InstanceView<'_> {
-    pub fn new(base: & InstanceBase) -> InstanceView {
+    pub fn new(base: & InstanceBase) -> InstanceView<'_> {
         let mut view = InstanceView {
             base,
             iteration: 0,
-            arguments: Vec::with_capacity(base.arguments.len()),
-            clauses: Vec::with_capacity(base.clauses.len()),
-            clauses_to_check: VecDeque::new()
+            arguments: Vec::with_capacity(base.number_of_variables),
+            clauses: Vec::with_capacity(base.get_number_of_clauses()),
+            clauses_to_check: VecDeque::new(),
+            required_positions: vec![None; base.number_of_variables]
         };
 
-        for _ in &base.arguments{
-            view.arguments.push(ArgumentView::new());
+        for (position, (argument, _)) in base.required_arguments.iter().enumerate() {
+            view.required_positions[*argument].get_or_insert(position);
         }
 
-        for clause in &base.clauses {
+        //The extension variables of the proof are stored after the arguments
+        view.arguments.resize_with(base.number_of_variables, ArgumentView::new);
+
+        for clause in base.framework.attacks.iter().chain(&base.proof_clauses) {
             let clause_view = ClauseView::new(clause, &mut view);
             view.clauses.push(clause_view);
         }
@@ -35,14 +43,19 @@ impl InstanceView<'_> {
         view
     }
 
-    pub fn reset(&mut self) {
+    /// Resets the assignment for the verification of the clause with the provided index and queues the unit clauses that precede it.
+    /// Deleted unit clauses are only queued if their deletion is ignored or reported as deleted reason by the deletion policy.
+    pub fn reset(&mut self, verification_index: usize) {
         self.iteration += 1;
         self.clauses_to_check.clear();
+        let warn_reasons = self.warns_on_deleted_reasons();
         for index in &self.base.unit_clauses {
-            self.clauses_to_check.push_back(*index);
+            if *index < verification_index && !self.base.is_attack_removed(*index) && (warn_reasons || self.clause_is_not_deleted_for(*index, verification_index)) {
+                self.clauses_to_check.push_back(*index);
+            }
         }
         for (argument, value) in &self.base.required_arguments {
-            self.set_argument_value(*argument, *value);
+            self.set_argument_value(*argument, *value, None);
         }
     }
 
@@ -53,11 +66,11 @@ impl InstanceView<'_> {
 
     #[inline]
     pub fn get_clause_members(&self, id: usize) -> &Vec<(usize, bool)> {
-        &self.clauses[id].get_members()
+        self.clauses[id].get_members()
     }
 
     pub fn check_clause_propagation(&mut self, id: usize) -> Option<(usize, bool)> {
-        let (result, first_watch_update, second_watch_update) = self.clauses[id].check_propagation(&self);
+        let (result, first_watch_update, second_watch_update) = self.clauses[id].check_propagation(self);
         if let Some(
             (watch_index,
             (remove_argument_index, remove_argument_sign),
@@ -82,8 +95,8 @@ impl InstanceView<'_> {
     }
 
     #[inline]
-    pub fn get_max_clause_index(&self) -> usize {
-        self.base.clauses.len() - 1
+    pub fn get_number_of_clauses(&self) -> usize {
+        self.base.get_number_of_clauses()
     }
 
     #[inline]
@@ -91,6 +104,16 @@ impl InstanceView<'_> {
         self.clauses[id].get_verifier()
     }
 
+    #[inline]
+    pub fn get_witness(&self, id: usize) -> Option<&Witness> {
+        self.clauses[id].get_witness()
+    }
+
+    #[inline]
+    pub fn get_required_arguments(&self) -> &Vec<(usize, bool)> {
+        &self.base.required_arguments
+    }
+
     #[inline]
     pub fn set_argument_watch(&mut self, add: bool, index: usize, clause_id: usize, sign: bool) {
         let argument = &mut self.arguments[index];
@@ -107,28 +130,103 @@ impl InstanceView<'_> {
         self.arguments[index].get_value(self.iteration)
     }
 
+    /// Assigns the value to the provided argument, where the reason is the clause that propagated it, if any.
     #[inline]
-    pub fn set_argument_value(&mut self, index: usize, value: bool,) {
-        self.arguments[index].set_value(value, self.iteration, &mut self.clauses_to_check);
+    pub fn set_argument_value(&mut self, index: usize, value: bool, reason: Option<usize>) {
+        self.arguments[index].set_value(value, reason, self.iteration, &mut self.clauses_to_check);
     }
 
     #[inline]
     pub fn set_clause_used(&self, index: usize) -> bool {
-        self.base.clauses[index].set_used()
+        self.base.clause_states[index].set_used()
     }
 
+    /// Marks the provided argument as used if it is required, as its value has been used during verification.
+    #[inline]
+    pub fn set_required_argument_used(&self, argument_index: usize) {
+        if let Some(position) = self.required_positions[argument_index] {
+            self.base.set_required_argument_used(position);
+        }
+    }
+
+    /// Marks the required arguments that the conflict on the provided argument depends on as used, where the reason is the clause that propagated the conflicting value, if any.
+    /// Starting from the conflict, the reasons of the propagated values are followed back, such that only the required arguments whose values lead to the conflict are marked.
+    pub fn set_conflict_reasons_used(&self, argument_index: usize, reason: Option<usize>) {
+        let mut visited = HashSet::from([argument_index]);
+        let mut arguments_todo = vec![argument_index];
+        let mut reasons_todo : Vec<usize> = reason.into_iter().collect();
+        loop {
+            if let Some(clause_index) = reasons_todo.pop() {
+                for (member, _) in self.get_clause_members(clause_index) {
+                    if visited.insert(*member) {
+                        arguments_todo.push(*member);
+                    }
+                }
+            }
+            else if let Some(argument_index) = arguments_todo.pop() {
+                match self.arguments[argument_index].get_reason(self.iteration) {
+                    Some(clause_index) => reasons_todo.push(clause_index),
+                    None => self.set_required_argument_used(argument_index)
+                }
+            }
+            else {
+                break;
+            }
+        }
+    }
+
+    /// Returns whether or not the clause can be used to verify the clause with the provided index, i.e. it is no removed attack and has not been deleted before or its deletion is ignored by the deletion policy.
     #[inline]
     pub fn clause_is_not_deleted_for(&self, clause_index: usize, verification_index: usize) -> bool {
-        if let Some(deletion_index) = self.clauses[clause_index].deleted_at() {
-            deletion_index <= verification_index
+        if self.base.is_attack_removed(clause_index) {
+            false
+        }
+        else if let Some(deletion_index) = self.base.clause_states[clause_index].deleted_at() {
+            verification_index < deletion_index || (self.base.deletion_policy == DeletionPolicy::IgnoreUnits && self.clauses[clause_index].get_members().len() == 1)
         }
-        else { 
-            true   
-        }        
+        else {
+            true
+        }
+    }
+
+    /// Returns whether or not deleted clauses that would have propagated an argument are reported.
+    #[inline]
+    pub fn warns_on_deleted_reasons(&self) -> bool {
+        self.base.deletion_policy == DeletionPolicy::WarnReasons
+    }
+
+    /// Returns whether or not the clause with the provided index may be verified as resolution asymmetric tautology.
+    /// This is the case if the semantics allow it or the first member of the clause is an extension variable, which is not contained in the encoding of any semantics.
+    #[inline]
+    pub fn allows_rat(&self, id: usize) -> bool {
+        self.base.allows_rat || self.get_clause_members(id)[0].0 >= self.base.framework.arguments.len()
+    }
+
+    /// Returns the indices of the clauses that contain the provided member and can be used to verify the clause with the provided index, in ascending order.
+    pub fn get_active_clauses_containing(&self, argument_index: usize, sign: bool, verification_index: usize) -> Vec<usize> {
+        self.base.get_occurrences(argument_index, sign).iter()
+            .take_while(|clause_index| **clause_index < verification_index)
+            .filter(|clause_index| self.clause_is_not_deleted_for(**clause_index, verification_index))
+            .copied()
+            .collect()
+    }
+
+    /// Returns the indices of the attacks that contain the provided argument, regardless of whether or not they have been deleted, in ascending order.
+    pub fn get_attacks_containing(&self, argument_index: usize) -> Vec<usize> {
+        self.base.get_occurrences(argument_index, false).iter()
+            .take_while(|clause_index| **clause_index < self.base.proof_start)
+            .copied()
+            .collect()
+    }
+
+    /// Returns whether or not the attack with the provided index has been removed from the framework, such that the implicit clauses need to ignore it.
+    #[inline]
+    pub fn is_attack_removed(&self, index: usize) -> bool {
+        self.base.is_attack_removed(index)
     }
 
     #[inline]
     pub fn get_attacked_by(&self, argument_index: usize) -> &Vec<usize> {
-        self.base.arguments[argument_index].get_attacked_by()
+        self.base.framework.arguments[argument_index].get_attacked_by()
     }
 }
\ No newline at end of file
@@ -1,22 +1,40 @@
 mod argument_base;
 mod argument_view;
+pub mod batch;
 mod clause_base;
 mod clause_view;
-mod instance_base;
+pub mod core_minimization;
+pub mod deletion_policy;
+pub mod dimacs;
+pub mod enumeration;
+pub mod extension_constraint;
+pub mod framework_base;
+pub mod instance_base;
 mod instance_view;
+pub mod optimality;
 pub mod parsers;
+pub mod query;
 pub mod semantics;
-mod supervisor;
+pub mod server;
+pub mod statistics;
+pub mod supervisor;
 mod verifiers;
+mod witness;
 mod worker;
 
 use std::path::PathBuf;
+use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use signal_hook::consts::{SIGALRM, SIGINT, SIGTERM, SIGUSR1};
-use signal_hook::iterator::{Signals, SignalsInfo};
-use once_cell::sync::OnceCell;
+use std::thread;
+use std::time::Duration;
+use signal_hook::consts::{SIGINT, SIGTERM, SIGUSR2};
+use signal_hook::iterator::Signals;
+use crate::deletion_policy::DeletionPolicy;
+use crate::extension_constraint::ExtensionConstraint;
+use crate::framework_base::FrameworkBase;
+use crate::parsers::file_reader::Source;
+use crate::parsers::format::FrameworkFormat;
 use crate::supervisor::{Supervisor, SupervisorState};
-use crate::instance_base::InstanceBase;
 use crate::semantics::Semantics;
 
 //Constants
@@ -31,12 +49,6 @@ pub const EXIT_CODE_UNEXPECTED: u8 = 128;
 // Whether or not we should terminate
 static DO_WORK : AtomicBool = AtomicBool::new(true);
 
-//The supervisor and schedules the worker threads
-pub static INSTANCE: OnceCell<Box<InstanceBase>> = OnceCell::new();
-
-// The instance we are working on
-pub static SUPERVISOR : OnceCell<Box<Supervisor>> = OnceCell::new();
-
 /// Returns Ok(false) if threads should continue working and Err(String) if the should terminate as soon as possible.
 pub fn should_stop() -> Result<bool, String> {
     match DO_WORK.load(Ordering::Acquire) {
@@ -45,47 +57,155 @@ pub fn should_stop() -> Result<bool, String> {
     }
 }
 
-pub fn verify(number_of_threads: u16, framework_path: PathBuf, description_path: Option<PathBuf>, required_arguments_path: Option<PathBuf>, proof_path: PathBuf, semantics: Semantics, complete: bool) -> (String, u8) {
+/// Tells all threads of the process to terminate as soon as possible.
+/// The runs that handle signals, i.e. verify, verify_with_framework, verify_batch and the server, call resume_all once they have terminated, such that a later run in the same process is not affected.
+pub fn stop_all() {
+    DO_WORK.store(false, Ordering::Release);
+}
+
+/// Allows the threads of the process to work again after stop_all has been called.
+pub fn resume_all() {
+    DO_WORK.store(true, Ordering::Release);
+}
+
+/// The input files and semantics of a single verification.
+#[derive(Clone)]
+pub struct VerificationJob {
+    /// The instance file.
+    pub framework: Source,
+    /// The format of the instance file, which is detected if not provided.
+    pub format: Option<FrameworkFormat>,
+    /// The instance description file.
+    pub description: Option<Source>,
+    /// The required arguments file.
+    pub required_arguments: Option<Source>,
+    /// The proof file.
+    pub proof: Source,
+    /// The semantics that the proof adheres to.
+    pub semantics: Semantics,
+    /// Whether or not the files are parsed strictly, i.e. tokens must be separated by single spaces and neither blank lines nor CRLF line endings are accepted.
+    pub strict: bool,
+    /// Whether or not every clause line of the proof starts with an ID, which deletions refer to instead of the members.
+    pub clause_ids: bool,
+    /// How the clause deletions of the proof are handled during verification.
+    pub deletion_policy: DeletionPolicy,
+    /// If provided, the proof shows that no extension satisfies this constraint, whose clauses are generated and trusted by the verifier.
+    pub extension_constraint: Option<ExtensionConstraint>
+}
+
+impl VerificationJob {
+
+    /// Creates the job for the proof of the instance without description, required arguments and extension constraint. The files are parsed leniently, the proof has no clause IDs and all deletions are honoured.
+    pub fn new(framework: Source, proof: Source, semantics: Semantics) -> VerificationJob {
+        VerificationJob {
+            framework,
+            format: None,
+            description: None,
+            required_arguments: None,
+            proof,
+            semantics,
+            strict: false,
+            clause_ids: false,
+            deletion_policy: DeletionPolicy::HonourAll,
+            extension_constraint: None
+        }
+    }
+}
+
+/// Verifies the proof for the given instance. While the verification is running, SIGUSR2 can be sent to print the current progress to stderr.
+///
+/// Returns the result message, the exit code and the supervisor of the verification, which can be queried for the detailed result.
+pub fn verify(number_of_threads: u16, framework_path: PathBuf, description_path: Option<PathBuf>, required_arguments_path: Option<PathBuf>, proof_path: PathBuf, semantics: Semantics, complete: bool) -> (String, u8, Arc<Supervisor>) {
+    let job = VerificationJob {
+        description: description_path.map(Source::Path),
+        required_arguments: required_arguments_path.map(Source::Path),
+        ..VerificationJob::new(Source::Path(framework_path), Source::Path(proof_path), semantics)
+    };
+    verify_with_framework(number_of_threads, job, None, complete, None, None)
+}
+
+/// Verifies the job like verify. If a framework is provided, it is used instead of parsing the instance and description files of the job, which allows to verify multiple proofs for the same framework without parsing it again.
+/// The verification is stopped once the timeout has been reached, if provided, and the progress is additionally printed to stderr in the progress interval, if provided.
+pub fn verify_with_framework(number_of_threads: u16, job: VerificationJob, framework: Option<Arc<FrameworkBase>>, complete: bool, timeout: Option<Duration>, progress: Option<Duration>) -> (String, u8, Arc<Supervisor>) {
 
     //Setup signal handlers
-    let signals = Signals::new(&[SIGINT, SIGTERM, SIGALRM, SIGUSR1]);
+    let signals = Signals::new([SIGINT, SIGTERM, SIGUSR2]);
     if let Err(err) = signals
     {
         eprintln!("Failed to setup signal handlers: {}", err);
         quit::with_code(EXIT_CODE_SETUP_SIGNALS);
     }
+    let mut signals = signals.unwrap();
+    let signals_handle = signals.handle();
 
-    let result = verify_internal(signals.unwrap(), number_of_threads, framework_path, description_path, required_arguments_path, proof_path, semantics, complete);
-    DO_WORK.store(false, Ordering::Release);
-    return result;
-}
+    let supervisor = Arc::new(Supervisor::new(number_of_threads));
+    let signal_supervisor = Arc::clone(&supervisor);
+
+    // Handle signals
+    let signal_thread = thread::spawn(move || {
+        for sig in signals.forever() {
+            match sig {
+                SIGUSR2 => //Progress requested
+                    {
+                        if let Some(progress) = signal_supervisor.get_progress() {
+                            eprintln!("{}", progress);
+                        }
+                    },
+                _ => { //Interrupted
+                        stop_all();
+                        signal_supervisor.stop(SupervisorState::Interrupted);
+                    }
+            }
+        }
+    });
+
+    // Report the progress periodically
+    let progress_thread = progress.map(|interval| {
+        let progress_supervisor = Arc::clone(&supervisor);
+        thread::spawn(move || {
+            while !progress_supervisor.wait(Some(interval)) {
+                if let Some(progress) = progress_supervisor.get_progress() {
+                    eprintln!("{}", progress);
+                }
+            }
+        })
+    });
 
-fn verify_internal(mut signals: SignalsInfo, number_of_threads: u16, framework_path: PathBuf, description_path: Option<PathBuf>, required_arguments_path: Option<PathBuf>, proof_path: PathBuf, semantics: Semantics, complete: bool) -> (String, u8) {
+    verify_job(&supervisor, job, framework, complete, timeout);
 
-    if SUPERVISOR.set(Box::new(Supervisor::new())).is_err() {
-        return (format!("Failed to initialize supervisor."), EXIT_CODE_UNEXPECTED);
+    signals_handle.close();
+    let signal_thread_result = signal_thread.join();
+    resume_all(); //The interruption only applies to this verification
+    if signal_thread_result.is_err() || progress_thread.is_some_and(|progress_thread| progress_thread.join().is_err()) {
+        return ("Failed to handle signals.".to_string(), EXIT_CODE_UNEXPECTED, supervisor);
     }
 
-    SUPERVISOR.get().unwrap().start(number_of_threads, framework_path, description_path, required_arguments_path, proof_path, semantics, complete);
-    // Handle signals
-    for sig in signals.forever() {
-        return match sig {
-            SIGALRM => (format!("Timeout reached."), EXIT_CODE_TIMEOUT),
-            SIGUSR1 => //Worker Finished
-                {
-                    match SUPERVISOR.get().unwrap().get_state() {
-                        SupervisorState::NotStarted => (format!("Verification has not been started."), EXIT_CODE_FAILURE),
-                        SupervisorState::Working => (format!("Verification has been interrupted unexpectedly."), EXIT_CODE_FAILURE),
-                        SupervisorState::ParsingFailed => (format!("Failed to parse the instance: {}", SUPERVISOR.get().unwrap().get_parsing_error_message()), EXIT_CODE_INSTANCE),
-                        SupervisorState::RequiredArgumentInconsistent => (format!("The required arguments are inconsistent."), EXIT_CODE_OK),
-                        SupervisorState::Finished => (format!("Finished."), EXIT_CODE_OK),
-                        SupervisorState::UnexpectedError => (format!("An unexpected error occurred."), EXIT_CODE_UNEXPECTED),
-                        SupervisorState::Unknown => (format!("Failed to determine the internal state."), EXIT_CODE_UNEXPECTED)
-                    }
-                },
-            _ => (format!("Interrupted by signal."), EXIT_CODE_SIGNALS) //Something unexpected happened
-        };
+    let (message, exit_code) = get_result_message(&supervisor);
+    (message, exit_code, supervisor)
+}
+
+/// Verifies a single job with the provided supervisor without handling any signals, which allows multiple verifications to run in the same process.
+///
+/// Returns once the verification has terminated, or stops it with the state TimedOut once the timeout has been reached and waits for its workers to abandon their checks.
+pub fn verify_job(supervisor: &Arc<Supervisor>, job: VerificationJob, framework: Option<Arc<FrameworkBase>>, complete: bool, timeout: Option<Duration>) {
+    supervisor.start(job, framework, complete);
+    if !supervisor.wait(timeout) {
+        supervisor.stop(SupervisorState::TimedOut);
+        supervisor.join_workers();
     }
+}
 
-    (format!("Did not receive signal"), EXIT_CODE_SIGNALS)
-}
\ No newline at end of file
+/// Returns the result message and exit code for the state the supervisor terminated in.
+pub fn get_result_message(supervisor: &Supervisor) -> (String, u8) {
+    match supervisor.get_state() {
+        SupervisorState::NotStarted => ("Verification has not been started.".to_string(), EXIT_CODE_FAILURE),
+        SupervisorState::Working => ("Verification has been interrupted unexpectedly.".to_string(), EXIT_CODE_FAILURE),
+        SupervisorState::ParsingFailed => (format!("Failed to parse the instance:\n{}", supervisor.get_parsing_error().get_report()), EXIT_CODE_INSTANCE),
+        SupervisorState::RequiredArgumentInconsistent => ("The required arguments are inconsistent.".to_string(), EXIT_CODE_OK),
+        SupervisorState::Finished => ("Finished.".to_string(), EXIT_CODE_OK),
+        SupervisorState::TimedOut => ("Timeout reached.".to_string(), EXIT_CODE_TIMEOUT),
+        SupervisorState::Interrupted => ("Interrupted by signal.".to_string(), EXIT_CODE_SIGNALS),
+        SupervisorState::UnexpectedError => ("An unexpected error occurred.".to_string(), EXIT_CODE_UNEXPECTED),
+        SupervisorState::Unknown => ("Failed to determine the internal state.".to_string(), EXIT_CODE_UNEXPECTED)
+    }
+}
@@ -1,16 +1,81 @@
 extern crate core;
-use std::{env, path::PathBuf, time::SystemTime};
-use clap::{Parser};
-use nix::unistd::alarm;
-use verifier::{verify, EXIT_CODE_OK, EXIT_CODE_UNEXPECTED, INSTANCE, SUPERVISOR, semantics::Semantics };
+use std::{env, ffi::OsString, fs::File, io, io::Write, path::{Path, PathBuf}, sync::Arc, time::{Duration, SystemTime}};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use verifier::{verify_with_framework, VerificationJob, EXIT_CODE_FAILURE, EXIT_CODE_INSTANCE, EXIT_CODE_OK, EXIT_CODE_SIGNALS, EXIT_CODE_TIMEOUT, EXIT_CODE_UNEXPECTED, semantics::Semantics };
+use verifier::supervisor::{Supervisor, SupervisorState};
+use verifier::parsers::file_reader::Source;
+use verifier::batch::{parse_manifest, verify_batch, write_summary};
+use verifier::server::{Server, ServeOptions};
+use verifier::deletion_policy::DeletionPolicy;
+use verifier::dimacs::DimacsEncoding;
+use verifier::extension_constraint::ExtensionConstraint;
+use verifier::framework_base::FrameworkBase;
+use verifier::instance_base::InstanceBase;
+use verifier::parsers::format::FrameworkFormat;
+use verifier::core_minimization::minimize_core;
+use verifier::enumeration::parse_extensions;
+use verifier::optimality::{check_extension, parse_extension};
+use verifier::parsers::parse_error::ParseError;
+use verifier::parsers::file_reader::set_memory_mapping;
+use verifier::query::Query;
+use verifier::statistics::{FrameworkStatistics, ProofStatistics};
 
 #[derive(Parser)]
 #[command(
     author = "Alexander Greßler <agressle@dbai.tuwien.ac.at>",
     version = env!("CARGO_PKG_VERSION"),
-    about = "A verifier of RUP proofs for unsatisfiability results of SETAF instances."
+    about = "A verifier of RUP proofs for unsatisfiability results of SETAF instances. When no command is given, verify is used."
 )]
-struct Args
+struct Cli
+{
+    #[command(subcommand)]
+    command: Command,
+
+    #[arg(
+        long = "mmap",
+        help = "When provided, uncompressed input files are memory-mapped instead of read as a stream. The files must not be truncated while the verifier runs, as this terminates it. Not supported by serve.",
+        required = false,
+        global = true,
+        default_value_t = false)
+    ]
+    mmap: bool
+}
+
+#[derive(Subcommand)]
+enum Command
+{
+    ///Verifies proofs for an instance.
+    Verify(VerifyArgs),
+    ///Verifies a proof and writes a proof that only contains the clauses used during verification.
+    Trim(ExtractArgs),
+    ///Verifies a proof and writes the framework that only contains the attacks used during verification.
+    Core(ExtractArgs),
+    ///Verifies a proof and writes the framework that only contains a set of attacks for which the proof is still verified, but not after removing any of them.
+    Minimize(ExtractArgs),
+    ///Verifies a proof and writes it as DRAT proof for the DIMACS encoding of the instance.
+    Drat(DratArgs),
+    ///Prints statistics about an instance and optionally a proof without verifying it.
+    Stats(InspectArgs),
+    ///Parses an instance and optionally a proof without verifying it, which checks their syntax and references, and reports inconsistent required arguments.
+    Lint(InspectArgs),
+    ///Converts an instance and optionally a proof between the supported formats.
+    Convert(ConvertArgs),
+    ///Exports the encoding of the semantics for an instance as DIMACS CNF.
+    Export(ExportArgs),
+    ///Verifies the jobs of a manifest and prints a summary of the verdicts.
+    Batch(BatchArgs),
+    ///Listens on a Unix domain socket and verifies the jobs it receives.
+    Serve(ServeArgs),
+    ///Verifies that a witness extension has the maximal number of arguments by a proof that no larger extension exists.
+    Optimum(ExtensionsArgs),
+    ///Verifies that a list of extensions is complete by a proof that no other extension exists.
+    Enumerate(ExtensionsArgs),
+    ///Answers whether an argument is credulously or skeptically accepted, which is certified by a witness extension or a proof.
+    Accept(AcceptArgs)
+}
+
+#[derive(Args)]
+struct FrameworkArgs
 {
     #[arg(
         short = 'i',
@@ -22,6 +87,14 @@ struct Args
     ///The path to the instance file.
     instance: PathBuf,
 
+    #[arg(
+        long = "instance-format",
+        help = "The format of the instance file. When omitted, the format is detected by the extension of the file or its content.",
+        required = false,
+        value_enum)
+    ]
+    instance_format: Option<FrameworkFormat>,
+
     #[arg(
         short = 'd',
         long = "description",
@@ -32,10 +105,17 @@ struct Args
     ///The path to the instance description file.
     description: Option<PathBuf>,
 
+    #[command(flatten)]
+    parsing: ParsingArgs
+}
+
+#[derive(Args)]
+struct ProofArgs
+{
     #[arg(
         short = 'p',
         long = "proof",
-        help = "A file that contains the proof.",
+        help = "A file that contains the proof, - to read it from stdin.",
         value_name = "FILE",
         required = true)
     ]
@@ -49,6 +129,7 @@ struct Args
         value_name = "FILE",
         required = false)
     ]
+    ///The path to the required arguments file.
     required: Option<PathBuf>,
 
     #[arg(
@@ -60,10 +141,41 @@ struct Args
     ]
     semantics: Semantics,
 
+    #[command(flatten)]
+    proof_format: ProofFormatArgs
+}
+
+#[derive(Args)]
+struct ParsingArgs
+{
+    #[arg(
+        long = "strict",
+        help = "When provided, tokens must be separated by single spaces and neither blank lines nor CRLF line endings are accepted.",
+        required = false,
+        default_value_t = false)
+    ]
+    strict: bool
+}
+
+#[derive(Args)]
+struct ProofFormatArgs
+{
+    #[arg(
+        long = "clause-ids",
+        help = "When provided, every clause line of the proofs starts with an ID, which deletions refer to instead of the members.",
+        required = false,
+        default_value_t = false)
+    ]
+    clause_ids: bool
+}
+
+#[derive(Args)]
+struct VerificationArgs
+{
     #[arg(
         short = 't',
         long = "timeout",
-        help = "The timeout in seconds 0 for no limit.",
+        help = "The timeout per verification of a proof in seconds 0 for no limit.",
         required = false,
         value_parser = clap::value_parser!(u32),
         default_value_t = 0)
@@ -73,7 +185,7 @@ struct Args
     #[arg(
         short = 'w',
         long = "threads",
-        help = "The number of verifier threads to use.",
+        help = "The number of verifier threads to use per verification of a proof.",
         required = false,
         value_parser = clap::value_parser!(u16).range(1..),
         default_value_t = 1)
@@ -81,96 +193,989 @@ struct Args
     thread: u16,
 
     #[arg(
-        short = 'u',
-        long = "used",
-        help = "When provided, indices of the attacks and clauses that where used during verification are printed.",
+        long = "deletion-policy",
+        help = "How clause deletions are handled: honour-all honours every deletion, ignore-units ignores deletions of unit clauses like drat-trim and warn-reasons honours every deletion but warns about deleted clauses that would have propagated.",
         required = false,
-        default_value_t = false)
+        value_enum,
+        default_value_t = DeletionPolicy::HonourAll)
     ]
-    used: bool,
+    deletion_policy: DeletionPolicy
+}
 
+impl VerificationArgs {
+
+    /// Returns the timeout or None if there is no limit.
+    fn get_timeout(&self) -> Option<Duration> {
+        if self.timeout != 0 { Some(Duration::from_secs(self.timeout as u64)) } else { None }
+    }
+}
+
+#[derive(Args)]
+struct CompletenessArgs
+{
     #[arg(
         short = 'c',
         long = "complete",
-        help = "When provided, all clauses of the proof are verified. Otherwise, only those used for propagation are verified.",
+        help = "When provided, all clauses of the proofs are verified. Otherwise, only those used for propagation are verified.",
         required = false,
         default_value_t = false)
     ]
     complete: bool
 }
 
+#[derive(Args)]
+struct VerifyArgs
+{
+    #[command(flatten)]
+    framework: FrameworkArgs,
+
+    #[arg(
+        short = 'p',
+        long = "proof",
+        help = "A file that contains the proof, - to read it from stdin. Can be provided multiple times to verify multiple proofs for the same instance, which is parsed only once.",
+        value_name = "FILE",
+        required = true)
+    ]
+    ///The paths to the proof files.
+    proof: Vec<PathBuf>,
+
+    #[arg(
+        short = 'r',
+        long = "required",
+        help = "A file that contains the required arguments. Either provided once for all proofs or once per proof.",
+        value_name = "FILE",
+        required = false)
+    ]
+    required: Vec<PathBuf>,
+
+    #[arg(
+        short = 's',
+        long = "semantics",
+        help = "The semantics that the proof adheres to. Either provided once for all proofs or once per proof.",
+        required = true,
+        value_enum)
+    ]
+    semantics: Vec<Semantics>,
+
+    #[command(flatten)]
+    proof_format: ProofFormatArgs,
+
+    #[command(flatten)]
+    verification: VerificationArgs,
+
+    #[command(flatten)]
+    completeness: CompletenessArgs,
+
+    #[arg(
+        short = 'P',
+        long = "progress",
+        help = "The interval in seconds in which the progress is printed to stderr, 0 for no periodic output. The progress can also be requested at any time by sending SIGUSR2.",
+        required = false,
+        value_parser = clap::value_parser!(u32),
+        default_value_t = 0)
+    ]
+    progress: u32,
+
+    #[arg(
+        short = 'u',
+        long = "used",
+        help = "When provided, indices of the attacks and clauses as well as the required arguments that where used during verification are printed.",
+        required = false,
+        default_value_t = false)
+    ]
+    used: bool
+}
+
+#[derive(Args)]
+struct ExtractArgs
+{
+    #[command(flatten)]
+    framework: FrameworkArgs,
+
+    #[command(flatten)]
+    proof: ProofArgs,
+
+    #[arg(
+        short = 'o',
+        long = "output",
+        help = "A file the result is written to instead of stdout.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the output file.
+    output: Option<PathBuf>,
+
+    #[command(flatten)]
+    verification: VerificationArgs
+}
+
+#[derive(Args)]
+struct DratArgs
+{
+    #[command(flatten)]
+    extract: ExtractArgs,
+
+    #[arg(
+        long = "cnf",
+        help = "A file the DIMACS encoding of the instance is written to, which the DRAT proof refers to.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the encoding file.
+    cnf: Option<PathBuf>
+}
+
+#[derive(Args)]
+struct InspectArgs
+{
+    #[command(flatten)]
+    framework: FrameworkArgs,
+
+    #[arg(
+        short = 'p',
+        long = "proof",
+        help = "A file that contains the proof, - to read it from stdin.",
+        value_name = "FILE",
+        required = false,
+        requires = "semantics")
+    ]
+    ///The path to the proof file.
+    proof: Option<PathBuf>,
+
+    #[arg(
+        short = 'r',
+        long = "required",
+        help = "A file that contains the required arguments.",
+        value_name = "FILE",
+        required = false,
+        requires = "proof")
+    ]
+    ///The path to the required arguments file.
+    required: Option<PathBuf>,
+
+    #[arg(
+        short = 's',
+        long = "semantics",
+        help = "The semantics that the proof adheres to.",
+        required = false,
+        requires = "proof",
+        value_enum)
+    ]
+    semantics: Option<Semantics>,
+
+    #[command(flatten)]
+    proof_format: ProofFormatArgs
+}
+
+impl InspectArgs {
+
+    /// Returns the proof arguments if a proof is provided.
+    fn get_proof(&self) -> Option<ProofArgs> {
+        match (&self.proof, &self.semantics) {
+            (Some(proof), Some(semantics)) => Some(ProofArgs { proof: proof.clone(), required: self.required.clone(), semantics: semantics.clone(), proof_format: ProofFormatArgs { clause_ids: self.proof_format.clause_ids } }),
+            _ => None
+        }
+    }
+}
+
+#[derive(Args)]
+struct ConvertArgs
+{
+    #[command(flatten)]
+    input: InspectArgs,
+
+    #[arg(
+        short = 'f',
+        long = "format",
+        help = "The format the instance is converted to.",
+        required = false,
+        value_enum,
+        default_value_t = FrameworkFormat::Ccl)
+    ]
+    format: FrameworkFormat,
+
+    #[arg(
+        short = 'o',
+        long = "output",
+        help = "A file the instance is written to instead of stdout.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the converted instance file.
+    output: Option<PathBuf>,
+
+    #[arg(
+        short = 'q',
+        long = "proof-output",
+        help = "A file the proof is written to. Required if a proof is provided.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the converted proof file.
+    proof_output: Option<PathBuf>
+}
+
+#[derive(Args)]
+struct ExportArgs
+{
+    #[command(flatten)]
+    framework: FrameworkArgs,
+
+    #[arg(
+        short = 'r',
+        long = "required",
+        help = "A file that contains the required arguments, which are exported as unit clauses.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the required arguments file.
+    required: Option<PathBuf>,
+
+    #[arg(
+        short = 's',
+        long = "semantics",
+        help = "The semantics that is encoded.",
+        required = true,
+        value_enum)
+    ]
+    semantics: Semantics,
+
+    #[arg(
+        short = 'o',
+        long = "output",
+        help = "A file the encoding is written to instead of stdout.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the output file.
+    output: Option<PathBuf>
+}
+
+#[derive(Args)]
+struct BatchArgs
+{
+    #[arg(
+        short = 'm',
+        long = "manifest",
+        help = "A file that contains one job per line, either as CSV (instance,description,required,proof,semantics,expected) or as JSON object with these keys.",
+        value_name = "FILE",
+        required = true)
+    ]
+    ///The path to the manifest file.
+    manifest: PathBuf,
+
+    #[arg(
+        short = 'o',
+        long = "output",
+        help = "A file the summary is written to instead of stdout.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the summary file.
+    output: Option<PathBuf>,
+
+    #[arg(
+        short = 'j',
+        long = "jobs",
+        help = "The number of jobs that are verified in parallel.",
+        required = false,
+        value_parser = clap::value_parser!(u16).range(1..),
+        default_value_t = 1)
+    ]
+    jobs: u16,
+
+    #[command(flatten)]
+    verification: VerificationArgs,
+
+    #[arg(
+        short = 'P',
+        long = "progress",
+        help = "The interval in seconds in which the number of finished jobs is printed to stderr, 0 for no periodic output. It can also be requested at any time by sending SIGUSR2.",
+        required = false,
+        value_parser = clap::value_parser!(u32),
+        default_value_t = 0)
+    ]
+    progress: u32,
+
+    #[command(flatten)]
+    completeness: CompletenessArgs,
+
+    #[command(flatten)]
+    parsing: ParsingArgs,
+
+    #[command(flatten)]
+    proof_format: ProofFormatArgs
+}
+
+#[derive(Args)]
+struct ServeArgs
+{
+    #[arg(
+        short = 'S',
+        long = "socket",
+        help = "The path of the Unix domain socket to listen on.",
+        value_name = "FILE",
+        required = true)
+    ]
+    ///The path to the socket.
+    socket: PathBuf,
+
+    #[arg(
+        short = 'j',
+        long = "jobs",
+        help = "The number of jobs that are verified in parallel.",
+        required = false,
+        value_parser = clap::value_parser!(u16).range(1..),
+        default_value_t = 1)
+    ]
+    jobs: u16,
+
+    #[command(flatten)]
+    verification: VerificationArgs,
+
+    #[arg(
+        short = 'C',
+        long = "cache-size",
+        help = "The maximal number of parsed frameworks that are cached, 0 to disable caching.",
+        required = false,
+        value_parser = clap::value_parser!(usize),
+        default_value_t = 16)
+    ]
+    cache_size: usize,
+
+    #[arg(
+        short = 'M',
+        long = "max-connections",
+        help = "The maximal number of connections that are handled at the same time. Further connections are rejected.",
+        required = false,
+        value_parser = clap::value_parser!(usize),
+        default_value_t = 64)
+    ]
+    max_connections: usize,
+
+    #[command(flatten)]
+    completeness: CompletenessArgs,
+
+    #[command(flatten)]
+    parsing: ParsingArgs
+}
+
+#[derive(Args)]
+struct CertificateArgs
+{
+    #[command(flatten)]
+    framework: FrameworkArgs,
+
+    #[arg(
+        short = 's',
+        long = "semantics",
+        help = "The semantics of the extensions.",
+        required = true,
+        value_enum)
+    ]
+    semantics: Semantics,
+
+    #[command(flatten)]
+    verification: VerificationArgs,
+
+    #[command(flatten)]
+    completeness: CompletenessArgs,
+
+    #[command(flatten)]
+    proof_format: ProofFormatArgs
+}
+
+#[derive(Args)]
+struct ExtensionsArgs
+{
+    #[command(flatten)]
+    certificate: CertificateArgs,
+
+    #[arg(
+        short = 'e',
+        long = "extensions",
+        help = "A file that contains the witness extension in the format of the required arguments for optimum, or the listed extensions, one per line followed by 0, for enumerate.",
+        value_name = "FILE",
+        required = true)
+    ]
+    ///The path to the extensions file.
+    extensions: PathBuf,
+
+    #[arg(
+        short = 'p',
+        long = "proof",
+        help = "A file that contains the proof that no other extension exists, - to read it from stdin.",
+        value_name = "FILE",
+        required = true)
+    ]
+    ///The path to the proof file.
+    proof: PathBuf
+}
+
+#[derive(Args)]
+struct AcceptArgs
+{
+    #[command(flatten)]
+    certificate: CertificateArgs,
+
+    #[arg(
+        long = "credulous",
+        help = "The number or name of the argument whose credulous acceptance is queried.",
+        value_name = "ARGUMENT",
+        required_unless_present = "skeptical",
+        conflicts_with = "skeptical")
+    ]
+    credulous: Option<String>,
+
+    #[arg(
+        long = "skeptical",
+        help = "The number or name of the argument whose skeptical acceptance is queried.",
+        value_name = "ARGUMENT",
+        required = false)
+    ]
+    skeptical: Option<String>,
+
+    #[arg(
+        short = 'e',
+        long = "extension",
+        help = "A file that contains the witness extension in the format of the required arguments, which shows credulous acceptance or rejects skeptical acceptance.",
+        value_name = "FILE",
+        required_unless_present = "proof",
+        conflicts_with = "proof")
+    ]
+    ///The path to the witness extension file.
+    extension: Option<PathBuf>,
+
+    #[arg(
+        short = 'p',
+        long = "proof",
+        help = "A file that contains the proof, which rejects credulous acceptance or shows skeptical acceptance, - to read it from stdin.",
+        value_name = "FILE",
+        required = false)
+    ]
+    ///The path to the proof file.
+    proof: Option<PathBuf>
+}
+
 #[quit::main]
 fn main() {
+    let cli = Cli::parse_from(with_default_command(env::args_os().collect()));
+    if cli.mmap {
+        if let Command::Serve(_) = cli.command {
+            println!("The option --mmap is not supported by serve, as files may change while they are cached.");
+            quit::with_code(EXIT_CODE_INSTANCE);
+        }
+        set_memory_mapping(true);
+    }
+    match cli.command {
+        Command::Verify(args) => run_verify(args),
+        Command::Trim(args) => run_extract(args, true),
+        Command::Core(args) => run_extract(args, false),
+        Command::Minimize(args) => run_minimize(args),
+        Command::Drat(args) => run_drat(args),
+        Command::Stats(args) => run_stats(args),
+        Command::Lint(args) => run_lint(args),
+        Command::Convert(args) => run_convert(args),
+        Command::Export(args) => run_export(args),
+        Command::Batch(args) => run_batch(args),
+        Command::Serve(args) => run_serve(args),
+        Command::Optimum(args) => run_optimum(args),
+        Command::Enumerate(args) => run_enumerate(args),
+        Command::Accept(args) => run_accept(args)
+    }
+}
 
-    let start_time : SystemTime = SystemTime::now();
+/// Inserts the verify command in front of the options if no command is given, so that verify can be called without naming it.
+fn with_default_command(mut arguments: Vec<OsString>) -> Vec<OsString> {
+    let cli = Cli::command();
+    let position = arguments.iter().skip(1).position(|argument| argument != "--mmap").map(|position| position + 1);
+    if let Some(position) = position {
+        let argument = arguments[position].to_string_lossy();
+        let is_command = cli.find_subcommand(argument.as_ref()).is_some() || ["help", "-h", "--help", "-V", "--version"].contains(&argument.as_ref());
+        if !is_command {
+            arguments.insert(position, OsString::from("verify"));
+        }
+    }
+    arguments
+}
 
-    let args = Args::parse();
+fn run_batch(args: BatchArgs) {
+    let mut jobs = match parse_manifest(&args.manifest) {
+        Ok(jobs) => jobs,
+        Err(error) => {
+            println!("Failed to parse the manifest:\n{}", error.get_report());
+            quit::with_code(EXIT_CODE_INSTANCE);
+        }
+    };
 
-    if args.timeout != 0 {
-        alarm::set(args.timeout);
+    for job in jobs.iter_mut() {
+        job.job.strict = args.parsing.strict;
+        job.job.clause_ids = args.proof_format.clause_ids;
+        job.job.deletion_policy = args.verification.deletion_policy;
     }
 
-    let (result_message, exit_code) = verify(args.thread, args.instance, args.description, args.required, args.proof, args.semantics, args.complete);
-    let end_time = SystemTime::now();
+    let progress = if args.progress != 0 { Some(Duration::from_secs(args.progress as u64)) } else { None };
+    let results = match verify_batch(&jobs, args.jobs, args.verification.thread, args.completeness.complete, args.verification.get_timeout(), progress) {
+        Ok(results) => results,
+        Err(message) => {
+            println!("{}", message);
+            quit::with_code(EXIT_CODE_UNEXPECTED);
+        }
+    };
+
+    let mut writer = create_writer(&args.output);
+    match write_summary(&jobs, &results, writer.as_mut()) {
+        Ok(0) => quit::with_code(EXIT_CODE_OK),
+        Ok(_) => quit::with_code(EXIT_CODE_FAILURE),
+        Err(err) => {
+            println!("Failed to write the summary: {}", err);
+            quit::with_code(EXIT_CODE_UNEXPECTED);
+        }
+    }
+}
+
+/// Returns a writer for the output file or stdout if none is provided.
+fn create_writer(output: &Option<PathBuf>) -> Box<dyn Write> {
+    match output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(io::BufWriter::new(file)),
+            Err(err) => {
+                println!("Failed to create the file {}: {}", path.display(), err);
+                quit::with_code(EXIT_CODE_UNEXPECTED);
+            }
+        },
+        None => Box::new(io::stdout())
+    }
+}
+
+/// Writes the output using the provided function and flushes it.
+fn write_output(output: &Option<PathBuf>, write: &dyn Fn(&mut dyn Write) -> io::Result<()>) {
+    let mut writer = create_writer(output);
+    if let Err(err) = write(writer.as_mut()).and_then(|_| writer.flush()) {
+        println!("Failed to write the output: {}", err);
+        quit::with_code(EXIT_CODE_UNEXPECTED);
+    }
+}
+
+/// Creates the job for the provided framework and proof.
+fn create_job(framework: &FrameworkArgs, proof: &ProofArgs, deletion_policy: DeletionPolicy) -> VerificationJob {
+    VerificationJob {
+        framework: Source::Path(framework.instance.clone()),
+        format: framework.instance_format,
+        description: framework.description.clone().map(Source::Path),
+        required_arguments: proof.required.clone().map(Source::Path),
+        proof: Source::from_argument(proof.proof.clone()),
+        semantics: proof.semantics.clone(),
+        strict: framework.parsing.strict,
+        clause_ids: proof.proof_format.clause_ids,
+        deletion_policy,
+        extension_constraint: None
+    }
+}
+
+/// Parses the framework and, if provided, the proof without verifying it. Exits if parsing fails.
+fn parse(framework: &FrameworkArgs, proof: &Option<ProofArgs>) -> (Arc<FrameworkBase>, Option<InstanceBase>) {
+    let parsed = FrameworkBase::new(&Source::Path(framework.instance.clone()), framework.instance_format, &framework.description.clone().map(Source::Path), framework.parsing.strict).map(Arc::new)
+        .and_then(|parsed_framework| match proof {
+            Some(proof) => InstanceBase::from_job(&create_job(framework, proof, DeletionPolicy::HonourAll), Some(Arc::clone(&parsed_framework))).map(|instance| (parsed_framework, Some(instance))),
+            None => Ok((parsed_framework, None))
+        });
+
+    match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            println!("Failed to parse the instance:\n{}", error.get_report());
+            quit::with_code(EXIT_CODE_INSTANCE);
+        }
+    }
+}
+
+fn run_extract(args: ExtractArgs, trim: bool) {
+    let supervisor = verify_for_extraction(&args);
+    let instance = supervisor.get_instance().unwrap();
+    let is_used = |index: usize| instance.clause_states[index].is_used();
+    if trim {
+        write_output(&args.output, &|writer| instance.write_proof(writer, &is_used));
+    }
+    else {
+        write_output(&args.output, &|writer| instance.framework.write(writer, &is_used));
+    }
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_drat(args: DratArgs) {
+    let supervisor = verify_for_extraction(&args.extract);
+    let instance = supervisor.get_instance().unwrap();
+    let is_used = |index: usize| instance.clause_states[index].is_used();
+    if let Some(index) = DimacsEncoding::find_clause_with_witness(instance, &is_used) {
+        println!("The {} has a witness, which cannot be expressed in a DRAT proof.", instance.describe_clause(index));
+        quit::with_code(EXIT_CODE_FAILURE);
+    }
 
+    let encoding = DimacsEncoding::from_instance(instance, &args.extract.proof.semantics);
+    if args.cnf.is_some() {
+        write_output(&args.cnf, &|writer| encoding.write(writer));
+    }
+    write_output(&args.extract.output, &|writer| encoding.write_drat(instance, writer, &is_used));
+    quit::with_code(EXIT_CODE_OK);
+}
+
+/// Verifies the proof of a command that extracts the used clauses or attacks and returns the supervisor of the successful verification. Exits otherwise.
+fn verify_for_extraction(args: &ExtractArgs) -> Arc<Supervisor> {
+    let (result_message, exit_code, supervisor) = verify_with_framework(args.verification.thread, create_job(&args.framework, &args.proof, args.verification.deletion_policy), None, false, args.verification.get_timeout(), None);
+    if exit_code != EXIT_CODE_OK {
+        println!("{}", result_message);
+        quit::with_code(exit_code);
+    }
+
+    if supervisor.get_state() == SupervisorState::RequiredArgumentInconsistent {
+        println!("The required arguments are inconsistent, thus no clauses were used.");
+        quit::with_code(EXIT_CODE_FAILURE);
+    }
+    if supervisor.get_result().is_none_or(|(verification_successful, _)| !verification_successful) {
+        print_result(&supervisor, false);
+        quit::with_code(EXIT_CODE_FAILURE);
+    }
+    supervisor
+}
+
+fn run_minimize(args: ExtractArgs) {
+    let instance = exit_on_parse_error(InstanceBase::from_job(&create_job(&args.framework, &args.proof, args.verification.deletion_policy), None));
+    let framework = Arc::clone(&instance.framework);
+
+    match minimize_core(args.verification.thread, instance, false, args.verification.get_timeout()) {
+        Ok(core) if core.minimal => {
+            write_output(&args.output, &|writer| framework.write(writer, &|index| core.attacks.binary_search(&index).is_ok()));
+            quit::with_code(EXIT_CODE_OK);
+        },
+        Ok(_) => {
+            println!("Timeout reached before the core was minimal for the proof.");
+            quit::with_code(EXIT_CODE_TIMEOUT);
+        },
+        Err((message, exit_code)) => {
+            println!("{}", message);
+            quit::with_code(exit_code);
+        }
+    }
+}
+
+fn run_stats(args: InspectArgs) {
+    let (framework, instance) = parse(&args.framework, &args.get_proof());
+    println!("{}", FrameworkStatistics::new(&framework));
+    if let Some(instance) = instance {
+        println!("{}", ProofStatistics::new(&instance));
+    }
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_lint(args: InspectArgs) {
+    let (_, instance) = parse(&args.framework, &args.get_proof());
+    if instance.is_some_and(|instance| !instance.is_required_arguments_consistent()) {
+        println!("The required arguments are inconsistent.");
+        quit::with_code(EXIT_CODE_FAILURE);
+    }
+    println!("No problems found.");
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_convert(args: ConvertArgs) {
+    let proof = args.input.get_proof();
+    if proof.is_some() && args.proof_output.is_none() {
+        println!("The proof output must be provided if a proof is converted.");
+        quit::with_code(EXIT_CODE_INSTANCE);
+    }
+
+    let (framework, instance) = parse(&args.input.framework, &proof);
+    write_output(&args.output, &|writer| framework.write_as(writer, args.format, &|_| true));
+    if let Some(instance) = instance {
+        write_output(&args.proof_output, &|writer| instance.write_proof(writer, &|_| true));
+    }
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_export(args: ExportArgs) {
+    let (framework, _) = parse(&args.framework, &None);
+    let required_arguments = match &args.required {
+        Some(required) => exit_on_parse_error(InstanceBase::parse_required(&Source::Path(required.clone()), framework.arguments.len(), &framework.argument_names, args.framework.parsing.strict)),
+        None => Vec::new()
+    };
+
+    let encoding = DimacsEncoding::new(&framework, &args.semantics, &required_arguments);
+    write_output(&args.output, &|writer| encoding.write(writer));
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_serve(args: ServeArgs) {
+    let options = ServeOptions {
+        parallel_jobs: args.jobs,
+        number_of_threads: args.verification.thread,
+        complete: args.completeness.complete,
+        timeout: args.verification.get_timeout(),
+        cache_size: args.cache_size,
+        max_connections: args.max_connections,
+        strict: args.parsing.strict,
+        deletion_policy: args.verification.deletion_policy
+    };
+
+    let result = Server::bind(&args.socket, options).and_then(|server| server.run());
+    if let Err(message) = result {
+        println!("{}", message);
+        quit::with_code(EXIT_CODE_UNEXPECTED);
+    }
+    quit::with_code(EXIT_CODE_OK);
+}
+
+/// Returns the parsed value or exits if parsing failed.
+fn exit_on_parse_error<T>(parsed: Result<T, ParseError>) -> T {
+    match parsed {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            println!("Failed to parse the instance:\n{}", error.get_report());
+            quit::with_code(EXIT_CODE_INSTANCE);
+        }
+    }
+}
+
+/// Parses the framework of the certificate. Exits if parsing fails.
+fn parse_certificate_framework(args: &CertificateArgs) -> Arc<FrameworkBase> {
+    exit_on_parse_error(FrameworkBase::new(&Source::Path(args.framework.instance.clone()), args.framework.instance_format, &args.framework.description.clone().map(Source::Path), args.framework.parsing.strict).map(Arc::new))
+}
+
+/// Checks the extension against the semantics of the certificate. Exits if it is invalid, in which case the message is prefixed by the description of the extension, if provided.
+fn check_certificate_extension(args: &CertificateArgs, framework: &FrameworkBase, extension: &[usize], description: Option<String>) {
+    if let Err(message) = check_extension(framework, extension, &args.semantics) {
+        match description {
+            Some(description) => println!("The {} is invalid: {}", description, message),
+            None => println!("{}", message)
+        }
+        quit::with_code(EXIT_CODE_FAILURE);
+    }
+}
+
+/// Verifies the proof of the certificate against the required arguments and the extension constraint, which shows that no extension satisfies both. Exits unless the proof is verified successfully.
+fn verify_certificate_proof(args: &CertificateArgs, framework: Arc<FrameworkBase>, proof: &Path, required_arguments: Option<Source>, constraint: Option<ExtensionConstraint>) {
+    let job = VerificationJob {
+        framework: Source::Path(args.framework.instance.clone()),
+        format: args.framework.instance_format,
+        description: args.framework.description.clone().map(Source::Path),
+        required_arguments,
+        proof: Source::from_argument(proof.to_path_buf()),
+        semantics: args.semantics.clone(),
+        strict: args.framework.parsing.strict,
+        clause_ids: args.proof_format.clause_ids,
+        deletion_policy: args.verification.deletion_policy,
+        extension_constraint: constraint
+    };
+    let (result_message, exit_code, supervisor) = verify_with_framework(args.verification.thread, job, Some(framework), args.completeness.complete, args.verification.get_timeout(), None);
     println!("{}", result_message);
-    let duration = end_time.duration_since(start_time).unwrap();
-    println!("Time: {},{}s", duration.as_secs(), duration.subsec_millis());
-
-    if exit_code == EXIT_CODE_OK {
-        let instance = INSTANCE.get().unwrap();
-        let result = SUPERVISOR.get().unwrap().get_result();
-        if let Some((verification_successful, clause_index)) = result {
-            if verification_successful {
-                println!("Proof verified successfully.");
-                if args.used {
-                    let mut first : bool = true;
-
-                    println!("The following attacks (0-based indices) of the instance were used during verification:");
-                    for clause in &instance.clauses[..instance.proof_start] {
-                        if clause.is_used() {
-                            if first {
-                                first = false;
-                            }
-                            else {
-                                print!(", ");
-                            }
-                            print!("{}", clause.get_index())
+    if exit_code != EXIT_CODE_OK {
+        quit::with_code(exit_code);
+    }
+
+    print_result(&supervisor, false);
+    if supervisor.get_result().is_none_or(|(verification_successful, _)| !verification_successful) {
+        quit::with_code(EXIT_CODE_FAILURE);
+    }
+}
+
+fn run_optimum(args: ExtensionsArgs) {
+    let framework = parse_certificate_framework(&args.certificate);
+    let extension = exit_on_parse_error(parse_extension(&Source::Path(args.extensions.clone()), &framework, args.certificate.framework.parsing.strict));
+
+    //First, the witness extension is checked against the semantics
+    check_certificate_extension(&args.certificate, &framework, &extension, None);
+    println!("Extension of size {} verified successfully.", extension.len());
+    if extension.len() == framework.arguments.len() {
+        println!("The extension is optimal, as it contains all arguments.");
+        quit::with_code(EXIT_CODE_OK);
+    }
+
+    //Second, the proof needs to show that no extension contains another argument
+    verify_certificate_proof(&args.certificate, framework, &args.proof, None, Some(ExtensionConstraint::MinimumSize(extension.len() + 1)));
+    println!("The extension is optimal, as no extension of size {} exists.", extension.len() + 1);
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_enumerate(args: ExtensionsArgs) {
+    let framework = parse_certificate_framework(&args.certificate);
+    let extensions = exit_on_parse_error(parse_extensions(&Source::Path(args.extensions.clone()), &framework, args.certificate.framework.parsing.strict));
+
+    //First, every listed extension is checked against the semantics
+    for (index, extension) in extensions.iter().enumerate() {
+        check_certificate_extension(&args.certificate, &framework, extension, Some(format!("listed extension with (0-based) index {}", index)));
+    }
+    println!("All {} listed extensions verified successfully.", extensions.len());
+
+    //Second, the proof needs to show that no other extension exists
+    let number_of_extensions = extensions.len();
+    verify_certificate_proof(&args.certificate, framework, &args.proof, None, Some(ExtensionConstraint::Blocked(extensions)));
+    println!("The list of extensions is complete, as no other extension exists besides the {} listed ones.", number_of_extensions);
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_accept(args: AcceptArgs) {
+    let framework = parse_certificate_framework(&args.certificate);
+    let query = match (&args.credulous, &args.skeptical) {
+        (Some(argument), _) => Query::Credulous(exit_on_parse_error(Query::parse_argument(argument, &framework))),
+        (None, Some(argument)) => Query::Skeptical(exit_on_parse_error(Query::parse_argument(argument, &framework))),
+        (None, None) => unreachable!("Clap requires one of the queries")
+    };
+
+    let answer = match (&args.extension, &args.proof) {
+        (Some(extension), _) => {
+            let extension = exit_on_parse_error(parse_extension(&Source::Path(extension.clone()), &framework, args.certificate.framework.parsing.strict));
+            check_certificate_extension(&args.certificate, &framework, &extension, None);
+            match query.answer_by_extension(&extension) {
+                Ok(answer) => answer,
+                Err(message) => {
+                    println!("{}", message);
+                    quit::with_code(EXIT_CODE_FAILURE);
+                }
+            }
+        },
+        (None, Some(proof)) => {
+            verify_certificate_proof(&args.certificate, framework, proof, Some(Source::from(query.to_required_arguments())), None);
+            query.answer_by_proof()
+        },
+        (None, None) => unreachable!("Clap requires one of the certificates")
+    };
+    println!("{}", query.describe_answer(answer, &args.certificate.semantics));
+    quit::with_code(EXIT_CODE_OK);
+}
+
+fn run_verify(args: VerifyArgs) {
+
+    let number_of_proofs = args.proof.len();
+    if args.required.len() > 1 && args.required.len() != number_of_proofs {
+        println!("The required arguments must be provided either once for all proofs or once per proof.");
+        quit::with_code(EXIT_CODE_INSTANCE);
+    }
+    if args.semantics.len() > 1 && args.semantics.len() != number_of_proofs {
+        println!("The semantics must be provided either once for all proofs or once per proof.");
+        quit::with_code(EXIT_CODE_INSTANCE);
+    }
+
+    let timeout = args.verification.get_timeout();
+    let progress = if args.progress != 0 { Some(Duration::from_secs(args.progress as u64)) } else { None };
+
+    //The framework is parsed during the first verification and then shared with the following ones
+    let mut framework = None;
+    let mut result_exit_code = EXIT_CODE_OK;
+    for (index, proof_path) in args.proof.iter().enumerate() {
+        let start_time : SystemTime = SystemTime::now();
+
+        let proof = ProofArgs {
+            proof: proof_path.clone(),
+            required: args.required.get(index).or(args.required.first()).cloned(),
+            semantics: args.semantics.get(index).unwrap_or(&args.semantics[0]).clone(),
+            proof_format: ProofFormatArgs { clause_ids: args.proof_format.clause_ids }
+        };
+        let job = create_job(&args.framework, &proof, args.verification.deletion_policy);
+
+        if number_of_proofs > 1 {
+            if index > 0 {
+                println!();
+            }
+            println!("Proof: {}", proof_path.display());
+        }
+
+        let (result_message, exit_code, supervisor) = verify_with_framework(args.verification.thread, job, framework.clone(), args.completeness.complete, timeout, progress);
+        let end_time = SystemTime::now();
+
+        println!("{}", result_message);
+        let duration = end_time.duration_since(start_time).unwrap();
+        println!("Time: {},{}s", duration.as_secs(), duration.subsec_millis());
+
+        if exit_code == EXIT_CODE_OK {
+            print_result(&supervisor, args.used);
+        }
+        else if result_exit_code == EXIT_CODE_OK {
+            result_exit_code = exit_code;
+        }
+
+        if exit_code == EXIT_CODE_SIGNALS {
+            break; //The remaining proofs are not verified
+        }
+
+        if framework.is_none() {
+            framework = supervisor.get_instance().map(|instance| Arc::clone(&instance.framework));
+        }
+    }
+    quit::with_code(result_exit_code);
+}
+
+/// Prints whether or not the proof was verified successfully and which attacks and proof clauses were used, followed by a warning for every deleted clause that would have propagated an argument.
+fn print_result(supervisor: &Supervisor, used: bool) {
+    let instance = supervisor.get_instance().unwrap();
+    let result = supervisor.get_result();
+    if let Some((verification_successful, clause_index)) = result {
+        if verification_successful {
+            println!("Proof verified successfully.");
+            if used {
+                let mut first : bool = true;
+
+                println!("The following attacks (0-based indices) of the instance were used during verification:");
+                for (index, clause_state) in instance.clause_states[..instance.proof_start].iter().enumerate() {
+                    if clause_state.is_used() {
+                        if first {
+                            first = false;
+                        }
+                        else {
+                            print!(", ");
                         }
+                        print!("{}", index)
                     }
+                }
 
-                    println!();
+                println!();
+                if instance.clause_ids.is_some() {
+                    println!("The following clauses (IDs) of the proof were used during verification:");
+                }
+                else {
                     println!("The following clauses (0-based indices) of the proof were used during verification:");
-                    first = true;
-
-                    for clause in &instance.clauses[instance.proof_start..] {
-                        if clause.is_used() {
-                            if first {
-                                first = false;
-                            }
-                            else {
-                                print!(", ");
-                            }
-                            print!("{}", clause.get_index() - instance.proof_start)
+                }
+                first = true;
+
+                for (index, clause_state) in instance.clause_states.iter().enumerate().skip(instance.proof_start) {
+                    if clause_state.is_used() {
+                        if first {
+                            first = false;
+                        }
+                        else {
+                            print!(", ");
+                        }
+                        match instance.get_clause_id(index) {
+                            Some(id) => print!("{}", id),
+                            None => print!("{}", index - instance.proof_start)
                         }
                     }
-                    println!();
                 }
-            }
-            else {
-                print!("Proof verification failed for ");
-                if let Some(clause_index) = clause_index {
-                    println!("the proof clause with (0-based) index {}.", clause_index - instance.proof_start)
-                }
-                else {
-                    println!("the empty clause.")
+                println!();
+
+                if !instance.required_arguments.is_empty() {
+                    println!("The following required arguments were used during verification:");
+                    let used_required_arguments : Vec<String> = instance.get_used_required_arguments().iter().map(|(argument, sign)| format!("{}{}", if *sign { "" } else { "-" }, argument + 1)).collect();
+                    println!("{}", used_required_arguments.join(", "));
                 }
             }
         }
         else {
-            println!("Failed to get result");
-            quit::with_code(EXIT_CODE_UNEXPECTED);
+            print!("Proof verification failed for ");
+            if let Some(clause_index) = clause_index {
+                println!("the {}.", instance.describe_clause(clause_index))
+            }
+            else {
+                println!("the empty clause.")
+            }
+        }
+
+        for (clause_index, verification_index) in supervisor.get_deleted_reasons() {
+            let verified = if verification_index < instance.get_number_of_clauses() { instance.describe_clause(verification_index) } else { "empty clause".to_string() };
+            println!("Warning: The deleted {} would have propagated an argument during the verification of the {}.", instance.describe_clause(clause_index), verified);
         }
     }
-    quit::with_code(exit_code);
-}
\ No newline at end of file
+    else {
+        println!("Failed to get result");
+        quit::with_code(EXIT_CODE_UNEXPECTED);
+    }
+}
@@ -0,0 +1,88 @@
+use crate::framework_base::FrameworkBase;
+use crate::instance_base::InstanceBase;
+use crate::parsers::file_reader::Source;
+use crate::parsers::parse_error::ParseError;
+use crate::semantics::Semantics;
+
+/// Parses the file of a witness extension, which lists the arguments of the extension in the format of the required arguments file, i.e. one argument number or 's <name>' per line.
+/// Returns the indices of the arguments, which need to be unique and must not be negated.
+pub fn parse_extension(source: &Source, framework: &FrameworkBase, strict: bool) -> Result<Vec<usize>, ParseError> {
+    let arguments = InstanceBase::parse_required(source, framework.arguments.len(), &framework.argument_names, strict)?;
+    let file = source.get_path().map(|path| path.display().to_string()).unwrap_or_default();
+
+    let mut extension = Vec::with_capacity(arguments.len());
+    for (argument, sign) in arguments {
+        if !sign {
+            return Err(ParseError::in_file(&file, format!("The extension file contains the negated argument '-{}'", argument + 1)));
+        }
+        if extension.contains(&argument) {
+            return Err(ParseError::in_file(&file, format!("The extension file contains the argument '{}' more than once", argument + 1)));
+        }
+        extension.push(argument);
+    }
+    Ok(extension)
+}
+
+/// Checks that the provided arguments form an extension of the framework for the semantics, which is done directly on the attacks.
+/// Returns a description of the violated condition otherwise.
+pub fn check_extension(framework: &FrameworkBase, extension: &[usize], semantics: &Semantics) -> Result<(), String> {
+    let mut contained = vec![false; framework.arguments.len()];
+    for argument in extension {
+        contained[*argument] = true;
+    }
+    let attackers_contained = |attack: usize| framework.attacks[attack].get_members()[1..].iter().all(|(attacker, _)| contained[*attacker]);
+    let is_attacked = |argument: usize| framework.arguments[argument].get_attacked_by().iter().any(|attack| attackers_contained(*attack));
+
+    for argument in extension {
+        if let Some(attack) = framework.arguments[*argument].get_attacked_by().iter().find(|attack| attackers_contained(**attack)) {
+            return Err(format!("The extension is not conflict-free, as it contains the argument '{}' as well as the attackers of the attack '{}'", argument + 1, framework.attacks[*attack].to_attack_line()));
+        }
+    }
+
+    match semantics {
+        Semantics::ConflictFree => Ok(()),
+        Semantics::Admissible => {
+            for argument in extension {
+                for attack in framework.arguments[*argument].get_attacked_by() {
+                    if !framework.attacks[*attack].get_members()[1..].iter().any(|(attacker, _)| is_attacked(*attacker)) {
+                        return Err(format!("The extension is not admissible, as it does not defend the argument '{}' against the attack '{}'", argument + 1, framework.attacks[*attack].to_attack_line()));
+                    }
+                }
+            }
+            Ok(())
+        },
+        Semantics::Stable => {
+            match (0..framework.arguments.len()).find(|argument| !contained[*argument] && !is_attacked(*argument)) {
+                Some(argument) => Err(format!("The extension is not stable, as it neither contains nor attacks the argument '{}'", argument + 1)),
+                None => Ok(())
+            }
+        }
+    }
+}
+
+/// Returns the clauses of a cardinality constraint that requires an extension to contain at least minimum arguments, where minimum must be between 1 and the number of arguments.
+/// The constraint is encoded by a sequential counter: the auxiliary variable s(i, j) implies that at least j of the first i arguments are contained, and s(n, minimum) is required for the n arguments.
+/// The auxiliary variables are numbered consecutively after the arguments, ordered by i and then by j, where j ranges from max(1, minimum - n + i) to min(i, minimum), as the other counters are not needed.
+pub fn encode_at_least(number_of_arguments: usize, minimum: usize) -> Vec<Vec<(usize, bool)>> {
+    let mut clauses = Vec::new();
+    let mut next_variable = number_of_arguments;
+    let mut previous : Vec<Option<usize>> = vec![None; minimum + 1]; //The variables s(i - 1, j) by j
+    for i in 1..=number_of_arguments {
+        let mut current = vec![None; minimum + 1];
+        for j in (minimum + i).saturating_sub(number_of_arguments).max(1)..=i.min(minimum) {
+            let counter = next_variable;
+            next_variable += 1;
+            current[j] = Some(counter);
+
+            //Either at least j of the first i - 1 arguments are contained, or the i-th argument and at least j - 1 of the first i - 1 arguments are
+            let carried = previous[j].map(|variable| (variable, true));
+            clauses.push([(counter, false)].into_iter().chain(carried).chain([(i - 1, true)]).collect());
+            if j > 1 {
+                clauses.push([(counter, false)].into_iter().chain(carried).chain(previous[j - 1].map(|variable| (variable, true))).collect());
+            }
+        }
+        previous = current;
+    }
+    clauses.push(vec![(previous[minimum].unwrap(), true)]);
+    clauses
+}
@@ -1 +1,9 @@
-pub mod file_reader;
\ No newline at end of file
+pub(crate) mod apx;
+pub(crate) mod ccl;
+pub mod file_reader;
+pub mod format;
+pub(crate) mod iccma;
+pub(crate) mod number;
+pub mod parse_error;
+pub(crate) mod tgf;
+pub mod tokenizer;
\ No newline at end of file
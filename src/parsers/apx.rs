@@ -0,0 +1,176 @@
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::io::Write;
+use crate::clause_base::ClauseBase;
+use crate::framework_base::{FrameworkBase, FrameworkBuilder};
+use crate::parsers::file_reader::FileReader;
+use crate::parsers::parse_error::ParseError;
+use crate::should_stop;
+
+///A fact of an apx file together with its position.
+struct Fact {
+    predicate: Predicate,
+    terms: Vec<String>,
+    line_number: usize,
+    column: usize
+}
+
+///The predicates of the apx format.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Predicate {
+    ///arg(a) declares the argument a.
+    Arg,
+    ///att(a,b) is an attack of the argument or collective attack a on the argument b.
+    Att,
+    ///mem(r,a) declares the argument a as member of the collective attack r.
+    Mem
+}
+
+///Parses a framework in the ASPARTIX apx format, i.e. facts arg(a), att(a,b) and, for collective attacks, att(r,b) together with mem(r,a) for every attacker a of r. Text after % is a comment.
+///The arguments are numbered in the order of their declaration and their names are used as argument names.
+pub(crate) fn parse(instance_reader: FileReader) -> Result<FrameworkBuilder, ParseError> {
+
+    let file = instance_reader.get_name().to_string();
+
+    //Read the facts, which can be stated in any order
+    let mut facts = Vec::new();
+    instance_reader.for_each_line(|line_number, line| {
+
+        should_stop()?;
+
+        parse_line(line, line_number, &mut facts).map_err(|(token, message)| ParseError::at_token(&file, line_number, line, token, message))
+    })?;
+
+    let error = |fact: &Fact, message: String| ParseError { line_number: Some(fact.line_number), column: Some(fact.column), ..ParseError::in_file(&file, message) };
+
+    //Number the arguments
+    let mut arguments : HashMap<&str, usize> = HashMap::new();
+    for fact in facts.iter().filter(|fact| fact.predicate == Predicate::Arg) {
+        let index = arguments.len();
+        if arguments.insert(&fact.terms[0], index).is_some() {
+            return Err(error(fact, format!("The argument '{}' is declared multiple times", fact.terms[0])));
+        }
+    }
+    let argument_index = |fact: &Fact, term: usize| match arguments.get(fact.terms[term].as_str()) {
+        Some(index) => Ok(*index),
+        None => Err(error(fact, format!("The fact references the argument '{}' that is not declared", fact.terms[term])))
+    };
+
+    //Collect the members of the collective attacks
+    let mut members : HashMap<&str, (Vec<usize>, bool)> = HashMap::new();
+    for fact in facts.iter().filter(|fact| fact.predicate == Predicate::Mem) {
+        if arguments.contains_key(fact.terms[0].as_str()) {
+            return Err(error(fact, format!("The collective attack '{}' has the name of an argument", fact.terms[0])));
+        }
+        let member = argument_index(fact, 1)?;
+        members.entry(&fact.terms[0]).or_insert((Vec::new(), false)).0.push(member);
+    }
+
+    let mut builder = FrameworkBuilder::new(arguments.len());
+    for (name, index) in &arguments {
+        builder.add_name(name, *index);
+    }
+
+    //Add the attacks in the order of their facts
+    for fact in facts.iter().filter(|fact| fact.predicate == Predicate::Att) {
+        let attacked = argument_index(fact, 1)?;
+        match members.get_mut(fact.terms[0].as_str()) {
+            Some((attackers, used)) => {
+                *used = true;
+                builder.add_attack(attacked, attackers.iter().copied());
+            },
+            None => builder.add_attack(attacked, [argument_index(fact, 0)?])
+        }
+    }
+
+    //Members of collective attacks that attack no argument are most likely a mistake
+    if let Some(fact) = facts.iter().find(|fact| fact.predicate == Predicate::Mem && members.get(fact.terms[0].as_str()).is_some_and(|(_, used)| !used)) {
+        return Err(error(fact, format!("The collective attack '{}' does not attack any argument", fact.terms[0])));
+    }
+
+    Ok(builder)
+}
+
+///Parses the facts of a line. Errors contain the offending part of the line.
+fn parse_line<'a>(line: &'a str, line_number: usize, facts: &mut Vec<Fact>) -> Result<(), (&'a str, String)> {
+    let mut rest = line;
+    loop {
+        rest = rest.trim_start();
+        if rest.is_empty() || rest.starts_with('%') {
+            return Ok(());
+        }
+
+        let malformed = |fact: &'a str| (fact, format!("The fact '{}' is malformed", fact.trim_end()));
+        let (open, close) = match (rest.find('('), rest.find(')')) {
+            (Some(open), Some(close)) if open < close => (open, close),
+            _ => return Err(malformed(rest))
+        };
+        let end = match rest[close + 1..].trim_start().strip_prefix('.') {
+            Some(after) => rest.len() - after.len(),
+            None => return Err(malformed(rest))
+        };
+
+        let (predicate, arity) = match rest[..open].trim_end() {
+            "arg" => (Predicate::Arg, 1),
+            "att" => (Predicate::Att, 2),
+            "mem" => (Predicate::Mem, 2),
+            name => return Err((rest, format!("The fact '{}' has the unknown predicate '{}'", &rest[..end], name)))
+        };
+        let terms : Vec<String> = rest[open + 1..close].split(',').map(|term| term.trim().to_string()).collect();
+        if terms.len() != arity || terms.iter().any(String::is_empty) {
+            return Err(malformed(&rest[..end]));
+        }
+
+        facts.push(Fact { predicate, terms, line_number, column: ParseError::get_column(line, rest) });
+        rest = &rest[end..];
+    }
+}
+
+///Writes the attacks of the framework in the apx format. The names of the arguments are used if every argument has a unique one, which is a valid constant.
+///Attacks with a single attacker are written as att(a,b), all others as collective attacks.
+pub(crate) fn write(framework: &FrameworkBase, attacks: &[&ClauseBase], writer: &mut dyn Write) -> io::Result<()> {
+    let names = framework.get_unique_names();
+    let names : Vec<String> = if names.iter().all(|name| name.is_some_and(is_valid_constant)) {
+        names.into_iter().map(|name| name.unwrap().to_string()).collect()
+    }
+    else {
+        (1..=framework.arguments.len()).map(|number| format!("a{}", number)).collect()
+    };
+
+    //Collective attacks must not have the name of an argument
+    let argument_names : HashSet<&str> = names.iter().map(String::as_str).collect();
+    let mut attack_names = Vec::new();
+    for attack in attacks {
+        let mut name = format!("r{}", attack.get_index() + 1);
+        while argument_names.contains(name.as_str()) {
+            name.push('_');
+        }
+        attack_names.push(name);
+    }
+
+    for name in &names {
+        writeln!(writer, "arg({}).", name)?;
+    }
+    for (attack, attack_name) in attacks.iter().zip(&attack_names) {
+        match attack.get_members().as_slice() {
+            [(attacked, _), (attacker, _)] => writeln!(writer, "att({},{}).", names[*attacker], names[*attacked])?,
+            [(attacked, _), attackers @ ..] => {
+                writeln!(writer, "att({},{}).", attack_name, names[*attacked])?;
+                for (attacker, _) in attackers {
+                    writeln!(writer, "mem({},{}).", attack_name, names[*attacker])?;
+                }
+            },
+            [] => {}
+        }
+    }
+    Ok(())
+}
+
+///Returns whether or not the name is a valid constant, i.e. a non-negative integer or a lowercase letter followed by alphanumeric characters or underscores.
+fn is_valid_constant(name: &str) -> bool {
+    match name.chars().next() {
+        Some(first) if first.is_ascii_digit() => name.chars().all(|c| c.is_ascii_digit()),
+        Some(first) if first.is_ascii_lowercase() => name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+        _ => false
+    }
+}
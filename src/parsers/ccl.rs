@@ -0,0 +1,133 @@
+use std::io;
+use std::io::Write;
+use crate::clause_base::ClauseBase;
+use crate::framework_base::{FrameworkBase, FrameworkBuilder};
+use crate::parsers::file_reader::FileReader;
+use crate::parsers::number::parse_usize;
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tokenizer::Tokenizer;
+use crate::should_stop;
+
+///Parses a framework in the instance file format, i.e. a preamble with the number of arguments and attacks followed by one line per attack, which contains the attacked argument, the attackers and 0.
+pub(crate) fn parse(instance_reader: FileReader) -> Result<FrameworkBuilder, ParseError> {
+
+    let file = instance_reader.get_name().to_string();
+    let tokenizer = instance_reader.get_tokenizer();
+
+    let mut preamble_read = None;
+    let mut content_length = 0;
+    let mut tokens = Vec::new();
+    let mut attackers = Vec::new();
+    instance_reader.for_each_line(|line_number, line| {
+
+        should_stop()?;
+        content_length += line.len() + 1;
+
+        let split = tokenizer.tokenize_reusing(line, std::mem::take(&mut tokens));
+        let (builder, num_attacks) = match &mut preamble_read {
+            Some((builder, num_attacks, _)) => (builder, *num_attacks),
+            None => {
+                let (builder, num_attacks) = parse_preamble(&file, line_number, line, &split)?;
+                preamble_read = Some((builder, num_attacks, (line_number, line.to_string())));
+                tokens = Tokenizer::recycle(split);
+                return Ok(());
+            }
+        };
+
+        //Check that we dont exceed the specified number of attacks
+        if builder.get_number_of_attacks() == num_attacks {
+            return Err(ParseError::at(&file, line_number, line, None, "Instance contains more attacks than specified in the preamble".to_string()))
+        }
+
+        if split.len() < 3 || *split.last().unwrap() != "0" { //We need at least the attacked argument, at least one attacker and the trailing 0
+            return Err(ParseError::at(&file, line_number, line, None, format!("The attack '{}' is malformed", line)))
+        }
+        let num_arguments = builder.get_number_of_arguments();
+
+        //Read the attacked argument
+        let attacked_arg_str = split[0];
+        let attacked_arg_number = parse_usize(attacked_arg_str);
+        if attacked_arg_number.is_none() {
+            return Err(ParseError::at_token(&file, line_number, line, attacked_arg_str, format!("The attacked argument index '{}' is invalid in line '{}'", attacked_arg_str, line)));
+        }
+        let attacked_arg_number: usize = attacked_arg_number.unwrap();
+        if attacked_arg_number == 0 || attacked_arg_number > num_arguments {
+            return Err(ParseError::at_token(&file, line_number, line, attacked_arg_str, format!("The attacked argument index '{}' is invalid in line '{}'", attacked_arg_str, line)));
+        }
+
+        //Read the remaining attack members
+        attackers.clear();
+        for attack_member_str in &split[1..] {
+            //Parse the attack member
+            let attack_member_number = parse_usize(attack_member_str);
+            if attack_member_number.is_none() {
+                return Err(ParseError::at_token(&file, line_number, line, attack_member_str, format!("The attack '{}' contains an invalid attack member '{}'", line, attack_member_str)));
+            }
+            let attack_member_number: usize = attack_member_number.unwrap();
+
+            //If 0 we assume we have reached the end and exit the loop
+            if attack_member_number == 0 {
+                break;
+            }
+
+            if attack_member_number > num_arguments {
+                return Err(ParseError::at_token(&file, line_number, line, attack_member_str, format!("The attack '{}' refers to an invalid attack member '{}'", line, attack_member_str)));
+            }
+            attackers.push(attack_member_number - 1);
+        }
+
+        builder.add_attack(attacked_arg_number - 1, attackers.iter().copied());
+        tokens = Tokenizer::recycle(split);
+        Ok(())
+    })?;
+
+    let (builder, num_attacks, (preamble_number, preamble)) = match preamble_read {
+        Some(preamble_read) => preamble_read,
+        None => return Err(ParseError::in_file(&file, "The supplied instance contains no preamble".to_string()))
+    };
+
+    //Check that there are no attacks left after reading the instance
+    if builder.get_number_of_attacks() != num_attacks {
+        return Err(ParseError::in_file(&file, "Instance contains fewer attacks than specified in the preamble".to_string()))
+    }
+
+    if !builder.fits_content(content_length) {
+        let split = tokenizer.tokenize(&preamble);
+        return Err(ParseError::at_token(&file, preamble_number, &preamble, split[0], format!("The number of arguments in the preamble is too large for an instance of {} bytes: {}", content_length, split[0])));
+    }
+
+    Ok(builder)
+}
+
+///Parses the preamble and returns the builder for the arguments together with the number of attacks.
+fn parse_preamble(file: &str, preamble_number: usize, preamble: &str, split: &[&str]) -> Result<(FrameworkBuilder, usize), ParseError> {
+    if split.len() != 3 || split[2] != "0" {
+        return Err(ParseError::at(file, preamble_number, preamble, None, format!("Preamble is malformed: {}", preamble)));
+    }
+
+    //Parse number of arguments
+    let num_arguments = parse_usize(split[0]);
+    if num_arguments.is_none() {
+        return Err(ParseError::at_token(file, preamble_number, preamble, split[0], format!("The number of arguments in the preamble is invalid: {}", split[0])));
+    }
+    let num_arguments :usize = num_arguments.unwrap();
+
+    //Parse number of attacks
+    let num_attacks = parse_usize(split[1]);
+    if num_attacks.is_none() {
+        return Err(ParseError::at_token(file, preamble_number, preamble, split[1], format!("The number of attacks in the preamble is invalid: {}", split[1])));
+    }
+
+    let num_attacks :usize = num_attacks.unwrap();
+
+    Ok((FrameworkBuilder::new(num_arguments), num_attacks))
+}
+
+///Writes the attacks of the framework in the instance file format.
+pub(crate) fn write(framework: &FrameworkBase, attacks: &[&ClauseBase], writer: &mut dyn Write) -> io::Result<()> {
+    writeln!(writer, "{} {} 0", framework.arguments.len(), attacks.len())?;
+    for attack in attacks {
+        writeln!(writer, "{}", attack.to_attack_line())?;
+    }
+    Ok(())
+}
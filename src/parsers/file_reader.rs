@@ -1,55 +1,295 @@
+use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io;
-use std::io::{BufRead, BufReader, ErrorKind, Lines};
-use std::path::PathBuf;
+use std::io::{BufRead, BufReader, Cursor, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use flate2::bufread::MultiGzDecoder;
+use memmap2::Mmap;
+use xz2::bufread::XzDecoder;
 use crate::argument_base::ArgumentBase;
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tokenizer::Tokenizer;
 
-///Used to read instance and proof files.
+///The source of an input file, which is either a path or the content of the file itself.
+#[derive(Clone, Debug)]
+pub enum Source {
+    ///The file at the path.
+    Path(PathBuf),
+    ///The content of the file.
+    Content(Arc<[u8]>),
+    ///The standard input, which can only be read once.
+    Stdin
+}
+
+impl Source {
+
+    ///Returns the source for a path provided on the command line, where '-' refers to the standard input.
+    pub fn from_argument(path: PathBuf) -> Source {
+        if path.as_os_str() == "-" { Source::Stdin } else { Source::Path(path) }
+    }
+
+    ///Returns the path of the file or None if the content is provided inline or read from the standard input.
+    pub fn get_path(&self) -> Option<&PathBuf> {
+        match self {
+            Source::Path(path) => Some(path),
+            Source::Content(_) | Source::Stdin => None
+        }
+    }
+}
+
+impl From<PathBuf> for Source {
+    fn from(path: PathBuf) -> Self {
+        Source::Path(path)
+    }
+}
+
+impl From<String> for Source {
+    fn from(content: String) -> Self {
+        Source::Content(Arc::from(content.into_bytes()))
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Path(path) => write!(f, "{}", path.display()),
+            Source::Content(_) => write!(f, "<inline>"),
+            Source::Stdin => write!(f, "<stdin>")
+        }
+    }
+}
+
+///Whether or not uncompressed files are memory-mapped, see set_memory_mapping.
+static MEMORY_MAPPING: AtomicBool = AtomicBool::new(false);
+
+///Enables or disables memory-mapping of uncompressed files for the readers that are created afterwards. Mapping is disabled by default, as it is only sound if the files are not truncated while they are read.
+pub fn set_memory_mapping(enabled: bool) {
+    MEMORY_MAPPING.store(enabled, Ordering::Release);
+}
+
+///The magic bytes at the start of gzip compressed files.
+const GZIP_MAGIC: &[u8] = &[0x1f, 0x8b];
+///The magic bytes at the start of xz compressed files.
+const XZ_MAGIC: &[u8] = &[0xfd, b'7', b'z', b'X', b'Z', 0x00];
+///The magic bytes at the start of zstd compressed files.
+const ZSTD_MAGIC: &[u8] = &[0x28, 0xb5, 0x2f, 0xfd];
+
+///Used to read instance and proof files. Files that are compressed with gzip, xz or zstd are decompressed on the fly.
+///The lines are provided by for_each_line and find_line as slices of a buffer, such that no memory is allocated per line.
+///If enabled by set_memory_mapping, uncompressed files are memory-mapped and their lines are provided directly from the mapping, which is validated as UTF-8 once.
+///
+///Unless strict, carriage returns at the end of lines, blank lines and comments preceded by whitespace are tolerated.
 pub struct FileReader {
-    reader : Lines<BufReader<File>>
+    input: Input,
+    ///The name of the file used in errors.
+    name: String,
+    ///The physical number of the line that was read last, including comment lines.
+    line_number: usize,
+    ///The tokenizer for the lines of the file.
+    tokenizer: Tokenizer,
+    ///Whether or not lines starting with # are skipped.
+    skip_comments: bool
+}
+
+///The input of a FileReader.
+enum Input {
+    ///A stream, which is read line by line.
+    Stream(Box<dyn BufRead>),
+    ///Content that is completely available in memory together with the position of the next line and the length of its prefix that is valid UTF-8.
+    Memory(Memory, usize, usize)
+}
+
+///Content that is completely available in memory.
+enum Memory {
+    ///A memory-mapped file.
+    Mapped(Mmap),
+    ///Inline content.
+    Shared(Arc<[u8]>)
+}
+
+impl AsRef<[u8]> for Memory {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Memory::Mapped(mmap) => mmap,
+            Memory::Shared(content) => content
+        }
+    }
 }
 
 impl FileReader
 {
+    ///Creates a new instance of FileReader for the provided source.
+    ///
+    ///Returns Err with a description of the error or the instance.
+    pub fn open(source: &Source, strict: bool) -> Result<FileReader, ParseError> {
+        match source {
+            Source::Path(path) => Self::new(path, strict),
+            Source::Content(content) => Self::from_memory(Memory::Shared(Arc::clone(content)), source.to_string(), strict),
+            Source::Stdin => Self::from_reader(Box::new(io::stdin().lock()), source.to_string(), strict)
+        }
+    }
+
     ///Creates a new instance of FileReader.
     ///
     ///Returns Err with a description of the error or the instance.
-    pub fn new(path: &PathBuf) -> Result<FileReader, String> {
+    pub fn new(path: &PathBuf, strict: bool) -> Result<FileReader, ParseError> {
+        let name = path.display().to_string();
         match File::open(path) {
-            Ok(f) => Ok(FileReader { reader: io::BufReader::new(f).lines()}),
-            Err(e) if e.kind() == ErrorKind::NotFound => Err(format!("The file {} does not exist", path.display())),
-            Err(e) if e.kind() == ErrorKind::PermissionDenied => Err(format!("Required permissions to open the file {} are missing.", path.display())),
-            Err(e) if e.kind() == ErrorKind::InvalidInput => Err(format!("The path {} is invalid.", path.display())),
-            Err(e) => Err(format!("An unexpected error occurred while trying to open the file {}: {}.", path.display(), e))
+            Ok(f) => match Self::map(&f) {
+                Some(mmap) => Self::from_memory(Memory::Mapped(mmap), name, strict),
+                None => Self::from_reader(Box::new(BufReader::new(f)), name, strict)
+            },
+            Err(e) if e.kind() == ErrorKind::NotFound => Err(ParseError::in_file(&name, format!("The file {} does not exist", name))),
+            Err(e) if e.kind() == ErrorKind::PermissionDenied => Err(ParseError::in_file(&name, format!("Required permissions to open the file {} are missing.", name))),
+            Err(e) if e.kind() == ErrorKind::InvalidInput => Err(ParseError::in_file(&name, format!("The path {} is invalid.", name))),
+            Err(e) => Err(ParseError::in_file(&name, format!("An unexpected error occurred while trying to open the file {}: {}.", name, e)))
         }
     }
-}
 
-///Used to interpret a line of a proof.
-pub trait LineInterpreter {
-    fn interpret(&self, line: &PathBuf, arguments: &mut Vec<&mut ArgumentBase>);
-}
+    ///Memory-maps the file or returns None if mapping is disabled, it is not a non-empty regular file or mapping fails, in which case it is read as a stream.
+    fn map(file: &File) -> Option<Mmap> {
+        if !MEMORY_MAPPING.load(Ordering::Acquire) || !file.metadata().is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0) {
+            return None;
+        }
 
-impl Iterator for FileReader
-{
-    type Item = Result<String, String>;
+        //SAFETY: The mapping is only sound as long as the file is not truncated while it is mapped. Accessing a page beyond the new end of the file raises SIGBUS, which terminates the process.
+        //Other modifications only change the content that is read, like for a stream. As this cannot be guaranteed by the verifier, mapping is only done if the user opted in, which the serve mode does not allow.
+        unsafe { Mmap::map(file) }.ok()
+    }
+
+    fn from_memory(memory: Memory, name: String, strict: bool) -> Result<FileReader, ParseError> {
+        if Self::is_compressed(memory.as_ref()) {
+            return Self::from_reader(Box::new(Cursor::new(memory)), name, strict);
+        }
+        let valid_up_to = std::str::from_utf8(memory.as_ref()).map_or_else(|err| err.valid_up_to(), str::len);
+        Ok(FileReader { input: Input::Memory(memory, 0, valid_up_to), name, line_number: 0, tokenizer: Tokenizer::new(strict), skip_comments: true })
+    }
 
-    ///Advances the iterator, skipping comment lines in the instance description
-    fn next(&mut self) -> Option<Result<String, String>> {
-        let mut next = self.reader.next();
+    fn from_reader(reader: Box<dyn BufRead>, name: String, strict: bool) -> Result<FileReader, ParseError> {
+        match Self::decompress(reader) {
+            Ok(reader) => Ok(FileReader { input: Input::Stream(reader), name, line_number: 0, tokenizer: Tokenizer::new(strict), skip_comments: true }),
+            Err(e) => Err(ParseError::in_file(&name, format!("An unexpected error occurred while trying to read the file {}: {}.", name, e)))
+        }
+    }
+
+    ///Returns whether or not the content starts with the magic bytes of a supported compression.
+    fn is_compressed(start: &[u8]) -> bool {
+        [GZIP_MAGIC, XZ_MAGIC, ZSTD_MAGIC].iter().any(|magic| start.starts_with(magic))
+    }
+
+    ///Detects the compression of the content by its magic bytes and returns a reader for the decompressed content.
+    fn decompress(mut reader: Box<dyn BufRead>) -> io::Result<Box<dyn BufRead>> {
+        let start = reader.fill_buf()?;
+        if start.starts_with(GZIP_MAGIC) {
+            Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+        }
+        else if start.starts_with(XZ_MAGIC) {
+            Ok(Box::new(BufReader::new(XzDecoder::new_multi_decoder(reader))))
+        }
+        else if start.starts_with(ZSTD_MAGIC) {
+            Ok(Box::new(BufReader::new(zstd::Decoder::with_buffer(reader)?)))
+        }
+        else {
+            Ok(reader)
+        }
+    }
+
+    ///Returns the reader, which also returns lines starting with #, e.g. for formats in which they are not comments.
+    pub fn keep_comments(mut self) -> FileReader {
+        self.skip_comments = false;
+        self
+    }
+
+    ///Returns the name of the file used in errors.
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    ///Returns the tokenizer for the lines of the file.
+    pub fn get_tokenizer(&self) -> Tokenizer {
+        self.tokenizer
+    }
+
+    ///Calls the function with the physical line number and the content of every line that is not skipped, i.e. comments and, unless strict, blank lines, until it returns an error.
+    pub fn for_each_line(self, mut function: impl FnMut(usize, &str) -> Result<(), ParseError>) -> Result<(), ParseError> {
+        self.find_line(|line_number, line| function(line_number, line).map(|_| None::<()>)).map(|_| ())
+    }
+
+    ///Calls the function like for_each_line until it returns an error or a value, which is returned. Returns None if the function returned no value for any line.
+    ///No memory is allocated per line, as the lines of memory-mapped files are provided directly and those of streams are read into a single buffer.
+    pub fn find_line<T>(mut self, mut function: impl FnMut(usize, &str) -> Result<Option<T>, ParseError>) -> Result<Option<T>, ParseError> {
+        let mut buffer = Vec::new();
         loop {
-            match next {
-                None => return None,
-                Some(result) => match result {
-                    //Skip comment
-                    Ok(line) if line.starts_with("#") => next = self.reader.next(),
+            let (raw_line, validated) = match Self::read_raw_line(&mut self.input, &mut buffer) {
+                None => return Ok(None),
+                Some(Ok(raw_line)) => raw_line,
+                Some(Err(err)) => return Err(ParseError { line_number: Some(self.line_number + 1), ..ParseError::in_file(&self.name, format!("An unexpected error occurred: {}", err)) })
+            };
+            self.line_number += 1;
 
-                    //Non-comment line
-                    Ok(line) => return Some(Ok(line)),
+            if let Some(line) = Self::decode_line(raw_line, validated, &self.name, self.line_number, self.tokenizer, self.skip_comments)? {
+                if let Some(value) = function(self.line_number, line)? {
+                    return Ok(Some(value));
+                }
+            }
+        }
+    }
 
-                    Err(err) => return Some(Err(format!("An unexpected error occurred: {}", err)))
+    ///Returns the next physical line including its line ending, together with whether or not it is known to be valid UTF-8, or None at the end of the input. Lines of streams are read into the buffer.
+    fn read_raw_line<'a>(input: &'a mut Input, buffer: &'a mut Vec<u8>) -> Option<io::Result<(&'a [u8], bool)>> {
+        match input {
+            Input::Memory(memory, position, valid_up_to) => {
+                let memory : &'a Memory = memory;
+                let remainder = &memory.as_ref()[*position..];
+                if remainder.is_empty() {
+                    return None;
                 }
-            };
+                let length = remainder.iter().position(|byte| *byte == b'\n').map_or(remainder.len(), |index| index + 1);
+                *position += length;
+                Some(Ok((&remainder[..length], *position <= *valid_up_to)))
+            },
+            Input::Stream(reader) => {
+                buffer.clear();
+                match reader.read_until(b'\n', buffer) {
+                    Ok(0) => None,
+                    Ok(_) => Some(Ok((buffer, false))),
+                    Err(err) => Some(Err(err))
+                }
+            }
+        }
+    }
+
+    ///Returns the line without its line ending or None if it is skipped, i.e. a comment or, unless strict, a blank line. Lines that are not known to be valid UTF-8 are validated.
+    fn decode_line<'a>(mut raw_line: &'a [u8], validated: bool, name: &str, line_number: usize, tokenizer: Tokenizer, skip_comments: bool) -> Result<Option<&'a str>, ParseError> {
+        if let Some(stripped) = raw_line.strip_suffix(b"\n") {
+            raw_line = stripped;
+            if !tokenizer.is_strict() {
+                raw_line = raw_line.strip_suffix(b"\r").unwrap_or(raw_line);
+            }
+        }
+
+        let line = if validated {
+            //SAFETY: The line is part of the prefix of the content that has been validated as UTF-8 and it is split at ASCII characters, which are never part of multibyte sequences.
+            unsafe { std::str::from_utf8_unchecked(raw_line) }
+        }
+        else {
+            match std::str::from_utf8(raw_line) {
+                Ok(line) => line,
+                Err(_) => return Err(ParseError { line_number: Some(line_number), ..ParseError::in_file(name, "The line is not valid UTF-8".to_string()) })
+            }
+        };
+
+        let content = if tokenizer.is_strict() { line } else { line.trim_start_matches(|c: char| c.is_ascii_whitespace()) };
+        if skip_comments && content.starts_with("#") || content.is_empty() && !tokenizer.is_strict() {
+            return Ok(None); //Skip comment or blank line
         }
+        Ok(Some(line))
     }
 }
+
+///Used to interpret a line of a proof.
+pub trait LineInterpreter {
+    fn interpret(&self, line: &Path, arguments: &mut Vec<&mut ArgumentBase>);
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+use clap::ValueEnum;
+use crate::parsers::file_reader::{FileReader, Source};
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tgf;
+
+///The supported file formats of frameworks.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, ValueEnum)]
+pub enum FrameworkFormat {
+    ///The instance file format, in which every attack is a line of the attacked argument followed by the attackers and 0.
+    Ccl,
+    ///The ICCMA 2023 format, i.e. a header 'p af <n>' followed by a line '<attacker> <attacked>' per attack.
+    Iccma,
+    ///The ASPARTIX format with the facts arg(a), att(a,b) and, for collective attacks, mem(r,a).
+    Apx,
+    ///The trivial graph format, i.e. a line per argument, a line '#' and a line '<attacker> <attacked>' per attack.
+    Tgf
+}
+
+impl FrameworkFormat {
+
+    ///Detects the format of the framework by the extension of its file, i.e. ccl, af, i23, apx or tgf, possibly followed by the extension of a compression, i.e. gz, xz or zst.
+    ///If the extension is unknown, the format is detected by the first line, where files that are neither in the ICCMA nor the apx format are considered trivial graph files if they contain a line '#' and instance files otherwise.
+    pub fn detect(source: &Source, strict: bool) -> Result<FrameworkFormat, ParseError> {
+        let extension = source.get_path().and_then(|path| match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gz" | "xz" | "zst") => path.file_stem().map(Path::new).and_then(Path::extension),
+            _ => path.extension()
+        }).and_then(|extension| extension.to_str());
+        if let Some(format) = extension.and_then(Self::from_extension) {
+            return Ok(format);
+        }
+
+        let reader = FileReader::open(source, strict)?;
+        let tokenizer = reader.get_tokenizer();
+        let format = reader.find_line(|_, first_line| {
+            let split = tokenizer.tokenize(first_line);
+            let trimmed_line = first_line.trim_start();
+
+            if split.first() == Some(&"p") {
+                Ok(Some(Some(FrameworkFormat::Iccma)))
+            }
+            else if ["arg(", "att(", "mem(", "%"].iter().any(|prefix| trimmed_line.starts_with(prefix)) {
+                Ok(Some(Some(FrameworkFormat::Apx)))
+            }
+            else if split.len() == 3 && split[2] == "0" {
+                Ok(Some(Some(FrameworkFormat::Ccl)))
+            }
+            else {
+                Ok(Some(None))
+            }
+        })?;
+
+        match format {
+            None => Ok(FrameworkFormat::Ccl),
+            Some(Some(format)) => Ok(format),
+            Some(None) => {
+                //Only trivial graph files are expected to contain the separator, which would be a comment otherwise
+                let reader = FileReader::open(source, strict)?.keep_comments();
+                let separator = reader.find_line(|_, line| Ok((line.trim() == tgf::SEPARATOR).then_some(())))?;
+                Ok(separator.map_or(FrameworkFormat::Ccl, |_| FrameworkFormat::Tgf))
+            }
+        }
+    }
+
+    ///Returns the format for the extension of a file or None if it is unknown.
+    pub fn from_extension(extension: &str) -> Option<FrameworkFormat> {
+        match extension.to_ascii_lowercase().as_str() {
+            "ccl" => Some(FrameworkFormat::Ccl),
+            "af" | "i23" => Some(FrameworkFormat::Iccma),
+            "apx" => Some(FrameworkFormat::Apx),
+            "tgf" => Some(FrameworkFormat::Tgf),
+            _ => None
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::io;
+use std::io::{ErrorKind, Write};
+use crate::clause_base::ClauseBase;
+use crate::framework_base::{FrameworkBase, FrameworkBuilder};
+use crate::parsers::file_reader::FileReader;
+use crate::parsers::number::parse_usize;
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tokenizer::Tokenizer;
+use crate::should_stop;
+
+///Parses a framework in the ICCMA 2023 format, i.e. a header 'p af <n>' followed by one line '<attacker> <attacked>' per attack.
+pub(crate) fn parse(instance_reader: FileReader) -> Result<FrameworkBuilder, ParseError> {
+
+    let file = instance_reader.get_name().to_string();
+    let tokenizer = instance_reader.get_tokenizer();
+
+    let mut header_read : Option<(FrameworkBuilder, (usize, String))> = None;
+    let mut content_length = 0;
+    let mut tokens = Vec::new();
+    instance_reader.for_each_line(|line_number, line| {
+
+        should_stop()?;
+        content_length += line.len() + 1;
+
+        let split = tokenizer.tokenize_reusing(line, std::mem::take(&mut tokens));
+        let (builder, num_arguments) = match &mut header_read {
+            Some((builder, _)) => {
+                let num_arguments = builder.get_number_of_arguments();
+                (builder, num_arguments)
+            },
+            None => {
+                header_read = Some((parse_header(&file, line_number, line, &split)?, (line_number, line.to_string())));
+                tokens = Tokenizer::recycle(split);
+                return Ok(());
+            }
+        };
+
+        //Read the attack
+        if split.len() != 2 {
+            return Err(ParseError::at(&file, line_number, line, None, format!("The attack '{}' is malformed", line)))
+        }
+
+        let mut indices = [0_usize; 2];
+        for (index, argument_str) in indices.iter_mut().zip(&split) {
+            *index = match parse_usize(argument_str) {
+                Some(argument_number) if argument_number != 0 && argument_number <= num_arguments => argument_number - 1,
+                _ => return Err(ParseError::at_token(&file, line_number, line, argument_str, format!("The attack '{}' refers to an invalid argument '{}'", line, argument_str)))
+            };
+        }
+
+        let [attacker, attacked] = indices;
+        builder.add_attack(attacked, [attacker]);
+        tokens = Tokenizer::recycle(split);
+        Ok(())
+    })?;
+
+    let (builder, (header_number, header)) = match header_read {
+        Some(header_read) => header_read,
+        None => return Err(ParseError::in_file(&file, "The supplied instance contains no header".to_string()))
+    };
+
+    if !builder.fits_content(content_length) {
+        let split = tokenizer.tokenize(&header);
+        return Err(ParseError::at_token(&file, header_number, &header, split[2], format!("The number of arguments in the header is too large for an instance of {} bytes: {}", content_length, split[2])));
+    }
+
+    Ok(builder)
+}
+
+///Parses the header and returns the builder for the arguments.
+fn parse_header(file: &str, header_number: usize, header: &str, split: &[&str]) -> Result<FrameworkBuilder, ParseError> {
+    if split.len() != 3 || split[0] != "p" || split[1] != "af" {
+        return Err(ParseError::at(file, header_number, header, None, format!("Header is malformed: {}", header)));
+    }
+
+    match parse_usize(split[2]) {
+        Some(num_arguments) => Ok(FrameworkBuilder::new(num_arguments)),
+        None => Err(ParseError::at_token(file, header_number, header, split[2], format!("The number of arguments in the header is invalid: {}", split[2])))
+    }
+}
+
+///Writes the attacks of the framework in the ICCMA 2023 format. Fails if an attack does not have exactly one attacker.
+pub(crate) fn write(framework: &FrameworkBase, attacks: &[&ClauseBase], writer: &mut dyn Write) -> io::Result<()> {
+    writeln!(writer, "p af {}", framework.arguments.len())?;
+    for attack in attacks {
+        match attack.get_members().as_slice() {
+            [(attacked, _), (attacker, _)] => writeln!(writer, "{} {}", attacker + 1, attacked + 1)?,
+            _ => return Err(io::Error::new(ErrorKind::InvalidInput, format!("The attack '{}' does not have exactly one attacker and cannot be written in the ICCMA format", attack.to_attack_line())))
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,45 @@
+//! Parses numbers directly from the bytes of tokens, without validating them as UTF-8 first.
+//! The accepted syntax is that of str::parse, i.e. an optional sign followed by at least one decimal digit, where negative numbers are only accepted for signed types.
+
+///Parses the digits of a number, which must not be empty, or returns None if they contain a non-digit or the number exceeds max.
+#[inline]
+fn parse_digits(digits: &[u8], max: u64) -> Option<u64> {
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut number : u64 = 0;
+    for byte in digits {
+        let digit = byte.wrapping_sub(b'0');
+        if digit > 9 {
+            return None;
+        }
+        number = number.checked_mul(10)?.checked_add(digit as u64)?;
+    }
+
+    if number > max { None } else { Some(number) }
+}
+
+///Parses an unsigned 64-bit integer like str::parse::<u64>.
+#[inline]
+pub(crate) fn parse_u64(token: impl AsRef<[u8]>) -> Option<u64> {
+    let token = token.as_ref();
+    parse_digits(token.strip_prefix(b"+").unwrap_or(token), u64::MAX)
+}
+
+///Parses an unsigned integer like str::parse::<usize>.
+#[inline]
+pub(crate) fn parse_usize(token: impl AsRef<[u8]>) -> Option<usize> {
+    let token = token.as_ref();
+    parse_digits(token.strip_prefix(b"+").unwrap_or(token), usize::MAX as u64).map(|number| number as usize)
+}
+
+///Parses a signed integer like str::parse::<isize>.
+#[inline]
+pub(crate) fn parse_isize(token: impl AsRef<[u8]>) -> Option<isize> {
+    let token = token.as_ref();
+    match token.strip_prefix(b"-") {
+        Some(digits) => parse_digits(digits, isize::MIN.unsigned_abs() as u64).map(|number| (number as isize).wrapping_neg()),
+        None => parse_digits(token.strip_prefix(b"+").unwrap_or(token), isize::MAX as u64).map(|number| number as isize)
+    }
+}
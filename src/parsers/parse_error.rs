@@ -0,0 +1,78 @@
+use std::fmt::{Display, Formatter};
+
+///An error that occurred while parsing an input file, together with the position at which it occurred if known.
+#[derive(Clone, Debug)]
+pub struct ParseError {
+    ///The file in which the error occurred.
+    pub file: Option<String>,
+    ///The 1-based physical line number, which also counts comment lines.
+    pub line_number: Option<usize>,
+    ///The 1-based column of the offending token.
+    pub column: Option<usize>,
+    ///The content of the offending line.
+    pub line: Option<String>,
+    ///The description of the error.
+    pub message: String
+}
+
+impl ParseError {
+
+    ///Creates an error without position.
+    pub fn new(message: String) -> ParseError {
+        ParseError { file: None, line_number: None, column: None, line: None, message }
+    }
+
+    ///Creates an error that refers to a file as a whole.
+    pub fn in_file(file: &str, message: String) -> ParseError {
+        ParseError { file: Some(file.to_string()), ..ParseError::new(message) }
+    }
+
+    ///Creates an error that refers to a line of a file and, if provided, the token at the column.
+    pub fn at(file: &str, line_number: usize, line: &str, column: Option<usize>, message: String) -> ParseError {
+        ParseError { file: Some(file.to_string()), line_number: Some(line_number), column, line: Some(line.to_string()), message }
+    }
+
+    ///Creates an error that refers to the token of a line, which must be a slice of the line.
+    pub fn at_token(file: &str, line_number: usize, line: &str, token: &str, message: String) -> ParseError {
+        Self::at(file, line_number, line, Some(Self::get_column(line, token)), message)
+    }
+
+    ///Returns the 1-based column of the token, which must be a slice of the line.
+    pub fn get_column(line: &str, token: &str) -> usize {
+        (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize).min(line.len()) + 1
+    }
+
+    ///Returns a compiler-style report of the error, i.e. the position and message followed by the offending line, in which the column is marked.
+    pub fn get_report(&self) -> String {
+        match (&self.line, self.column) {
+            (Some(line), Some(column)) => {
+                let offset = line.char_indices().take_while(|(index, _)| *index < column - 1).count();
+                format!("{}\n    {}\n    {}^", self, line, " ".repeat(offset))
+            },
+            (Some(line), None) => format!("{}\n    {}", self, line),
+            _ => self.to_string()
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file)?;
+            if let Some(line_number) = self.line_number {
+                write!(f, "{}:", line_number)?;
+                if let Some(column) = self.column {
+                    write!(f, "{}:", column)?;
+                }
+            }
+            write!(f, " error: ")?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl From<String> for ParseError {
+    fn from(message: String) -> Self {
+        ParseError::new(message)
+    }
+}
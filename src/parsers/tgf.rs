@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::io;
+use std::io::{ErrorKind, Write};
+use crate::clause_base::ClauseBase;
+use crate::framework_base::{FrameworkBase, FrameworkBuilder};
+use crate::parsers::file_reader::FileReader;
+use crate::parsers::parse_error::ParseError;
+use crate::parsers::tokenizer::Tokenizer;
+use crate::should_stop;
+
+///The line that separates the nodes from the edges.
+pub(crate) const SEPARATOR: &str = "#";
+
+///Parses a framework in the trivial graph format, i.e. one line '<id> [label]' per argument, a line '#' and one line '<attacker id> <attacked id> [label]' per attack.
+///The ids are used as argument names. The reader must not skip lines starting with #.
+pub(crate) fn parse(instance_reader: FileReader) -> Result<FrameworkBuilder, ParseError> {
+
+    let file = instance_reader.get_name().to_string();
+    let tokenizer = instance_reader.get_tokenizer();
+
+    let mut ids : HashMap<String, usize> = HashMap::new();
+    let mut builder : Option<FrameworkBuilder> = None;
+    let mut tokens = Vec::new();
+    instance_reader.for_each_line(|line_number, line| {
+
+        should_stop()?;
+
+        let split = tokenizer.tokenize_reusing(line, std::mem::take(&mut tokens));
+
+        match &mut builder {
+            None if split.len() == 1 && split[0] == SEPARATOR => builder = Some(create_builder(&ids)),
+            None => { //Read a node
+                let id = split.first().copied().unwrap_or_default();
+                if id.is_empty() {
+                    return Err(ParseError::at(&file, line_number, line, None, format!("The node '{}' is malformed", line)))
+                }
+                let index = ids.len();
+                if ids.insert(id.to_string(), index).is_some() {
+                    return Err(ParseError::at_token(&file, line_number, line, id, format!("The node id '{}' is not unique", id)))
+                }
+            },
+            Some(builder) => { //Read an edge
+                if split.len() < 2 {
+                    return Err(ParseError::at(&file, line_number, line, None, format!("The edge '{}' is malformed", line)))
+                }
+
+                let mut indices = [0_usize; 2];
+                for (index, id) in indices.iter_mut().zip(&split) {
+                    *index = match ids.get(*id) {
+                        Some(index) => *index,
+                        None => return Err(ParseError::at_token(&file, line_number, line, id, format!("The edge '{}' refers to an invalid node id '{}'", line, id)))
+                    };
+                }
+
+                let [attacker, attacked] = indices;
+                builder.add_attack(attacked, [attacker]);
+            }
+        }
+        tokens = Tokenizer::recycle(split);
+        Ok(())
+    })?;
+
+    //A file without separator contains no edges
+    Ok(builder.unwrap_or_else(|| create_builder(&ids)))
+}
+
+///Creates the builder for the read nodes, using their ids as names.
+fn create_builder(ids: &HashMap<String, usize>) -> FrameworkBuilder {
+    let mut builder = FrameworkBuilder::new(ids.len());
+    for (id, index) in ids {
+        builder.add_name(id, *index);
+    }
+    builder
+}
+
+///Writes the attacks of the framework in the trivial graph format. The names of the arguments are used as ids if every argument has a unique one, which is a valid id. Fails if an attack does not have exactly one attacker.
+pub(crate) fn write(framework: &FrameworkBase, attacks: &[&ClauseBase], writer: &mut dyn Write) -> io::Result<()> {
+    let names = framework.get_unique_names();
+    let ids : Vec<String> = if names.iter().all(|name| name.is_some_and(is_valid_id)) {
+        names.into_iter().map(|name| name.unwrap().to_string()).collect()
+    }
+    else {
+        (1..=framework.arguments.len()).map(|number| number.to_string()).collect()
+    };
+
+    for id in &ids {
+        writeln!(writer, "{}", id)?;
+    }
+    writeln!(writer, "{}", SEPARATOR)?;
+    for attack in attacks {
+        match attack.get_members().as_slice() {
+            [(attacked, _), (attacker, _)] => writeln!(writer, "{} {}", ids[*attacker], ids[*attacked])?,
+            _ => return Err(io::Error::new(ErrorKind::InvalidInput, format!("The attack '{}' does not have exactly one attacker and cannot be written in the trivial graph format", attack.to_attack_line())))
+        }
+    }
+    Ok(())
+}
+
+///Returns whether or not the name can be used as node id.
+fn is_valid_id(name: &str) -> bool {
+    !name.is_empty() && name != SEPARATOR && !name.contains(|c: char| c.is_whitespace())
+}
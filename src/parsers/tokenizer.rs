@@ -0,0 +1,88 @@
+///Splits the lines of input files into tokens.
+///
+///Unless strict, tokens are separated by any ASCII whitespace. In strict mode, tokens are separated by single spaces, such that repeated, leading or trailing whitespace yields empty tokens.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Tokenizer {
+    strict: bool
+}
+
+impl Tokenizer {
+
+    ///Creates a new tokenizer.
+    pub fn new(strict: bool) -> Tokenizer {
+        Tokenizer { strict }
+    }
+
+    ///Returns whether or not the strict mode is used.
+    #[inline]
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    ///Returns the tokens of the line, which are slices of the line.
+    pub fn tokenize<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        if self.strict {
+            line.split(' ').collect()
+        }
+        else {
+            line.split_ascii_whitespace().collect()
+        }
+    }
+
+    ///Returns the tokens of the line like tokenize, but reuses the memory of the provided tokens of a previous line, such that lines can be tokenized without allocating memory.
+    pub fn tokenize_reusing<'a>(&self, line: &'a str, tokens: Vec<&str>) -> Vec<&'a str> {
+        let mut tokens = Self::recycle(tokens);
+        if self.strict {
+            tokens.extend(line.split(' '));
+        }
+        else {
+            tokens.extend(line.split_ascii_whitespace());
+        }
+        tokens
+    }
+
+    ///Returns the tokens of the line like tokenize_reusing, but whitespace between double quotes does not separate tokens, such that quoted names may contain spaces.
+    pub fn tokenize_quoted_reusing<'a>(&self, line: &'a str, tokens: Vec<&str>) -> Vec<&'a str> {
+        if !line.contains('"') {
+            return self.tokenize_reusing(line, tokens);
+        }
+
+        let mut tokens = Self::recycle(tokens);
+        let mut token_start = 0;
+        let mut quoted = false;
+        for (index, c) in line.char_indices() {
+            if c == '"' {
+                quoted = !quoted;
+            }
+            else if !quoted && (if self.strict { c == ' ' } else { c.is_ascii_whitespace() }) {
+                if self.strict || token_start < index {
+                    tokens.push(&line[token_start..index]);
+                }
+                token_start = index + 1;
+            }
+        }
+        if self.strict || token_start < line.len() {
+            tokens.push(&line[token_start..]);
+        }
+        tokens
+    }
+
+    ///Clears the tokens, such that their memory can be reused for the tokens of another line.
+    pub fn recycle<'a>(mut tokens: Vec<&str>) -> Vec<&'a str> {
+        tokens.clear();
+        //As both element types have the same layout, the memory is reused for the empty result
+        tokens.into_iter().map(|_| "").collect()
+    }
+
+    ///Returns the remainder of the line after the provided token, which must be a slice of the line, without the separating whitespace.
+    pub fn get_remainder<'a>(&self, line: &'a str, token: &str) -> &'a str {
+        let end = ((token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize) + token.len()).min(line.len());
+        let remainder = &line[end..];
+        if self.strict {
+            remainder.strip_prefix(' ').unwrap_or(remainder)
+        }
+        else {
+            remainder.trim_matches(|c: char| c.is_ascii_whitespace())
+        }
+    }
+}
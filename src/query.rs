@@ -0,0 +1,68 @@
+use clap::ValueEnum;
+use crate::framework_base::FrameworkBase;
+use crate::instance_base::InstanceBase;
+use crate::parsers::parse_error::ParseError;
+use crate::semantics::Semantics;
+
+/// A query for the acceptance of an argument under a semantics.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Query {
+    /// Whether or not some extension contains the argument.
+    Credulous(usize),
+    /// Whether or not every extension contains the argument.
+    Skeptical(usize)
+}
+
+impl Query {
+
+    /// Parses the argument of the query, which is given like a member of a proof clause, i.e. by its number or name.
+    pub fn parse_argument(argument: &str, framework: &FrameworkBase) -> Result<usize, ParseError> {
+        match InstanceBase::parse_proof_member(argument, argument, framework, framework.arguments.len()) {
+            Ok((index, true)) => Ok(index),
+            Ok((_, false)) => Err(ParseError::new(format!("The query refers to the negated argument '{}'", argument))),
+            Err(_) => Err(ParseError::new(format!("The query refers to the invalid argument '{}'", argument)))
+        }
+    }
+
+    #[inline]
+    pub fn get_argument(&self) -> usize {
+        match self {
+            Query::Credulous(argument) | Query::Skeptical(argument) => *argument
+        }
+    }
+
+    /// Returns the required arguments file that a proof is verified against, which shows that no extension is a counterexample to the answer of the proof:
+    /// A proof that no extension contains the argument rejects credulous acceptance, while a proof that no extension lacks the argument shows skeptical acceptance.
+    pub fn to_required_arguments(&self) -> String {
+        match self {
+            Query::Credulous(argument) => format!("{}\n", argument + 1),
+            Query::Skeptical(argument) => format!("-{}\n", argument + 1)
+        }
+    }
+
+    /// Returns the answer that is certified by a proof, which is verified against the required arguments of the query.
+    pub fn answer_by_proof(&self) -> bool {
+        matches!(self, Query::Skeptical(_))
+    }
+
+    /// Returns the answer that is certified by a witness extension, or an error if the extension does not certify an answer:
+    /// An extension that contains the argument shows credulous acceptance, while one that lacks it rejects skeptical acceptance.
+    pub fn answer_by_extension(&self, extension: &[usize]) -> Result<bool, String> {
+        match (self, extension.contains(&self.get_argument())) {
+            (Query::Credulous(_), true) => Ok(true),
+            (Query::Skeptical(_), false) => Ok(false),
+            (Query::Credulous(argument), false) => Err(format!("The witness extension does not contain the argument '{}', thus it does not show that the argument is credulously accepted", argument + 1)),
+            (Query::Skeptical(argument), true) => Err(format!("The witness extension contains the argument '{}', thus it does not show that the argument is not skeptically accepted", argument + 1))
+        }
+    }
+
+    /// Returns the answer to the query for the semantics as a sentence.
+    pub fn describe_answer(&self, answer: bool, semantics: &Semantics) -> String {
+        let (kind, quantifier) = match self {
+            Query::Credulous(_) => ("credulously", if answer { "some" } else { "no" }),
+            Query::Skeptical(_) => ("skeptically", if answer { "every" } else { "not every" })
+        };
+        let semantics = semantics.to_possible_value().map(|value| value.get_name().to_string()).unwrap_or_default();
+        format!("The argument '{}' is {}{} accepted under the semantics {}, as {} extension contains it.", self.get_argument() + 1, if answer { "" } else { "not " }, kind, semantics, quantifier)
+    }
+}
@@ -2,20 +2,24 @@ use clap::builder::PossibleValue;
 use clap::ValueEnum;
 
 use crate::parsers::file_reader::LineInterpreter;
+use crate::parsers::number::parse_usize;
 
 
 ///Represents the semantics of the instance
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum Semantics {
     ConflictFree,
     Admissible,
     Stable
 }
 
+#[derive(Clone)]
 pub enum VerifierType {
     RUP,
     Admissibility(Option<usize>),
-    Stability
+    Stability,
+    ///The clause is an assumption that is generated and trusted by the verifier, e.g. part of a constraint on the extensions, and thus not verified.
+    Assumption
 }
 
 pub trait LineInterpreterDeterminer {
@@ -38,47 +42,45 @@ impl ValueEnum for Semantics {
 
 impl Semantics {
 
-    /// Returns the starts index in the proof line and verifier to use for a given type of semantics and proof line.
-    pub fn get_verifier(&self, line: &String) -> Option<(usize, VerifierType)> {
+    /// Returns the verifier to use for a proof line with the provided first token for a given type of semantics, together with whether or not the token is the prefix of the verifier and thus not part of the clause.
+    pub fn get_verifier(&self, first_token: &str) -> Option<(bool, VerifierType)> {
         match self {
-            Semantics::ConflictFree => Some((0, VerifierType::RUP)),
-            Semantics::Admissible => Self::get_verifier_admissible(line),
-            Semantics::Stable => Self::get_verifier_stable(line),
+            Semantics::ConflictFree => Some((false, VerifierType::RUP)),
+            Semantics::Admissible => Self::get_verifier_admissible(first_token),
+            Semantics::Stable => Self::get_verifier_stable(first_token),
         }
     }
 
-    fn get_verifier_admissible(line: &String) -> Option<(usize, VerifierType)> {
-        match line {
-            l if l.starts_with("i") => {
+    /// Returns whether or not proof clauses may be verified as resolution asymmetric tautologies.
+    /// This requires that every clause of the encoding of the semantics is known, which only holds if the attacks are the complete encoding, i.e. for conflict-free sets.
+    pub fn allows_rat(&self) -> bool {
+        match self {
+            Semantics::ConflictFree => true,
+            Semantics::Admissible | Semantics::Stable => false
+        }
+    }
 
-                let first_space = l.find(' ');
-                if let Some(first_space) = first_space {
+    /// Returns the attackers of the attack that is named by the first token of an implicit admissibility clause, i.e. 'i{<attackers>}' with comma-separated argument numbers or names.
+    pub fn get_named_attackers<'a>(&self, first_token: &'a str) -> Option<&'a str> {
+        match self {
+            Semantics::Admissible => first_token.strip_prefix("i{").and_then(|attackers| attackers.strip_suffix('}')),
+            Semantics::ConflictFree | Semantics::Stable => None
+        }
+    }
 
-                    if first_space == 1 {
-                        Some((2, VerifierType::Admissibility(None)))
-                    }
-                    else {
-                        let index = l[1..=first_space].parse::<usize>();
-                        if let Ok(index) = index {
-                            Some((first_space + 1, VerifierType::Admissibility(Some(index))))
-                        }
-                        else {
-                            None
-                        }
-                    }
-                }
-                else {
-                    None
-                }
-            },
-            _ => Some((0, VerifierType::RUP))
+    fn get_verifier_admissible(first_token: &str) -> Option<(bool, VerifierType)> {
+        match first_token.strip_prefix('i') {
+            Some("") => Some((true, VerifierType::Admissibility(None))),
+            Some(attackers) if attackers.starts_with('{') && attackers.ends_with('}') => Some((true, VerifierType::Admissibility(None))), //The attack is resolved by get_named_attackers once the clause is parsed
+            Some(index) => parse_usize(index).map(|index| (true, VerifierType::Admissibility(Some(index)))),
+            None => Some((false, VerifierType::RUP))
         }
     }
 
-    fn get_verifier_stable(line: &String) -> Option<(usize, VerifierType)> {
-        match line {
-            l if l.starts_with("i ") => Some((2, VerifierType::Stability)),
-            _ => Some((0, VerifierType::RUP))
+    fn get_verifier_stable(first_token: &str) -> Option<(bool, VerifierType)> {
+        match first_token {
+            "i" => Some((true, VerifierType::Stability)),
+            _ => Some((false, VerifierType::RUP))
         }
     }
-}
\ No newline at end of file
+}
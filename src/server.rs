@@ -0,0 +1,424 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use signal_hook::consts::{SIGINT, SIGTERM, SIGUSR2};
+use signal_hook::iterator::Signals;
+use crate::batch::Verdict;
+use crate::deletion_policy::DeletionPolicy;
+use crate::framework_base::FrameworkBase;
+use crate::parsers::file_reader::Source;
+use crate::parsers::format::FrameworkFormat;
+use crate::parsers::parse_error::ParseError;
+use crate::semantics::Semantics;
+use crate::supervisor::Supervisor;
+use crate::{resume_all, should_stop, stop_all, verify_job, VerificationJob};
+
+/// The paths of the instance and description files of a cached framework together with the format of the instance and whether or not they are parsed strictly.
+type FrameworkKey = (PathBuf, Option<FrameworkFormat>, Option<PathBuf>, bool);
+
+/// The options of the server that are used unless specified otherwise by a request, i.e. complete, timeout, strict and the deletion policy.
+type RequestDefaults = (bool, Option<Duration>, bool, DeletionPolicy);
+
+/// The modification times of the instance and description files of a cached framework.
+type FrameworkVersion = (SystemTime, Option<SystemTime>);
+
+/// A framework in the cache.
+struct CacheEntry {
+    version: FrameworkVersion,
+    framework: Arc<FrameworkBase>,
+    last_used: Instant
+}
+
+/// A cache of parsed frameworks, which are identified by the paths and modification times of their instance and description files.
+pub struct FrameworkCache {
+    /// The maximal number of cached frameworks, 0 to disable caching.
+    capacity: usize,
+    entries: Mutex<HashMap<FrameworkKey, CacheEntry>>
+}
+
+impl FrameworkCache {
+
+    /// Creates a new cache that holds up to capacity frameworks.
+    pub fn new(capacity: usize) -> FrameworkCache {
+        FrameworkCache { capacity, entries: Mutex::new(HashMap::new()) }
+    }
+
+    /// Returns the framework for the provided files together with whether or not it was taken from the cache.
+    /// Frameworks that are provided inline are parsed but not cached. If the cache is full, the least recently used framework is evicted.
+    pub fn get(&self, framework: &Source, format: Option<FrameworkFormat>, description: &Option<Source>, strict: bool) -> Result<(Arc<FrameworkBase>, bool), ParseError> {
+        let key = match (framework, description) {
+            (Source::Path(framework_path), None) => (framework_path.clone(), format, None, strict),
+            (Source::Path(framework_path), Some(Source::Path(description_path))) => (framework_path.clone(), format, Some(description_path.clone()), strict),
+            _ => return FrameworkBase::new(framework, format, description, strict).map(|framework| (Arc::new(framework), false))
+        };
+
+        //If the files cannot be accessed, parsing reports the error
+        let version = match Self::get_version(&key) {
+            Some(version) if self.capacity > 0 => version,
+            _ => return FrameworkBase::new(framework, format, description, strict).map(|framework| (Arc::new(framework), false))
+        };
+
+        if let Some(entry) = self.entries.lock().map_err(|_| ParseError::new("Failed to access the framework cache.".to_string()))?.get_mut(&key) {
+            if entry.version == version {
+                entry.last_used = Instant::now();
+                return Ok((Arc::clone(&entry.framework), true));
+            }
+        }
+
+        //The lock is not held while parsing such that other jobs can access the cache meanwhile
+        let parsed = Arc::new(FrameworkBase::new(framework, format, description, strict)?);
+        let mut entries = self.entries.lock().map_err(|_| ParseError::new("Failed to access the framework cache.".to_string()))?;
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let least_recently_used = entries.iter().min_by_key(|(_, entry)| entry.last_used).map(|(key, _)| key.clone());
+            if let Some(least_recently_used) = least_recently_used {
+                entries.remove(&least_recently_used);
+            }
+        }
+        entries.insert(key, CacheEntry { version, framework: Arc::clone(&parsed), last_used: Instant::now() });
+        Ok((parsed, false))
+    }
+
+    /// Returns the number of cached frameworks.
+    pub fn len(&self) -> usize {
+        self.entries.lock().map_or(0, |entries| entries.len())
+    }
+
+    /// Returns whether or not the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the modification times of the files or None if they cannot be determined.
+    fn get_version(key: &FrameworkKey) -> Option<FrameworkVersion> {
+        let modified = |path: &PathBuf| fs::metadata(path).and_then(|metadata| metadata.modified()).ok();
+        let framework_modified = modified(&key.0)?;
+        let description_modified = match &key.2 {
+            Some(description_path) => Some(modified(description_path)?),
+            None => None
+        };
+        Some((framework_modified, description_modified))
+    }
+}
+
+/// A request that is sent to the server as a single line containing a JSON object.
+/// Every file is provided either by its path or by its content.
+#[derive(Deserialize)]
+struct Request {
+    id: Option<Value>,
+    instance: Option<PathBuf>,
+    instance_content: Option<String>,
+    format: Option<String>,
+    description: Option<PathBuf>,
+    description_content: Option<String>,
+    required: Option<PathBuf>,
+    required_content: Option<String>,
+    proof: Option<PathBuf>,
+    proof_content: Option<String>,
+    semantics: String,
+    complete: Option<bool>,
+    timeout: Option<u64>,
+    strict: Option<bool>,
+    clause_ids: Option<bool>,
+    deletion_policy: Option<String>
+}
+
+/// The response to a request that is sent back as a single line containing a JSON object.
+#[derive(Serialize)]
+struct Response {
+    id: Value,
+    verdict: String,
+    details: String,
+    time: f64,
+    cached: bool
+}
+
+/// A job that waits to be verified by the server.
+struct QueuedJob {
+    id: Value,
+    job: VerificationJob,
+    complete: bool,
+    timeout: Option<Duration>,
+    connection: Arc<Mutex<UnixStream>>
+}
+
+/// The options of the server.
+pub struct ServeOptions {
+    /// The number of jobs that are verified in parallel.
+    pub parallel_jobs: u16,
+    /// The number of verifier threads to use per job.
+    pub number_of_threads: u16,
+    /// Whether or not all clauses of the proofs are verified, unless specified otherwise by the request.
+    pub complete: bool,
+    /// The timeout per job, unless specified otherwise by the request.
+    pub timeout: Option<Duration>,
+    /// The maximal number of cached frameworks.
+    pub cache_size: usize,
+    /// The maximal number of connections that are handled at the same time. Further connections are rejected.
+    pub max_connections: usize,
+    /// Whether or not the files are parsed strictly, unless specified otherwise by the request.
+    pub strict: bool,
+    /// How clause deletions are handled, unless specified otherwise by the request.
+    pub deletion_policy: DeletionPolicy
+}
+
+/// A server that verifies the jobs it receives over a Unix domain socket.
+pub struct Server {
+    listener: UnixListener,
+    socket_path: PathBuf,
+    options: ServeOptions,
+    cache: FrameworkCache
+}
+
+impl Server {
+
+    /// Creates a server that listens on the socket at the provided path. A stale socket file is replaced.
+    pub fn bind(socket_path: &Path, options: ServeOptions) -> Result<Server, String> {
+        if socket_path.exists() {
+            if UnixStream::connect(socket_path).is_ok() {
+                return Err(format!("The socket {} is already in use", socket_path.display()));
+            }
+            fs::remove_file(socket_path).map_err(|err| format!("Failed to remove the stale socket {}: {}", socket_path.display(), err))?;
+        }
+
+        let listener = UnixListener::bind(socket_path).map_err(|err| format!("Failed to bind the socket {}: {}", socket_path.display(), err))?;
+        let cache = FrameworkCache::new(options.cache_size);
+        Ok(Server { listener, socket_path: socket_path.to_path_buf(), options, cache })
+    }
+
+    /// Accepts connections and verifies the received jobs until SIGINT or SIGTERM is received.
+    /// Every connection is handled by its own thread, where up to max_connections are handled at the same time. Once the server is stopped, the connections are shut down and their threads are joined.
+    /// While the server is running, SIGUSR2 can be sent to print the number of queued and finished jobs to stderr.
+    pub fn run(&self) -> Result<(), String> {
+
+        //Setup signal handlers
+        let mut signals = Signals::new([SIGINT, SIGTERM, SIGUSR2]).map_err(|err| format!("Failed to setup signal handlers: {}", err))?;
+        let signals_handle = signals.handle();
+
+        let (sender, receiver) = channel::<QueuedJob>();
+        let receiver = Mutex::new(receiver);
+        let queued_jobs = AtomicUsize::new(0);
+        let finished_jobs = AtomicUsize::new(0);
+        let connections : Mutex<HashMap<usize, UnixStream>> = Mutex::new(HashMap::new()); //The streams of the handled connections by their number, such that they can be shut down
+
+        thread::scope(|scope| {
+
+            // Handle signals
+            scope.spawn(|| {
+                for sig in signals.forever() {
+                    match sig {
+                        SIGUSR2 => eprintln!("Progress: {} queued jobs, {} finished jobs, {} cached frameworks", queued_jobs.load(Ordering::Acquire), finished_jobs.load(Ordering::Acquire), self.cache.len()),
+                        _ => { //Interrupted, close the connections and wake up the listener such that it notices
+                            stop_all();
+                            Self::shutdown_connections(&connections);
+                            _ = UnixStream::connect(&self.socket_path);
+                        }
+                    }
+                }
+            });
+
+            for _ in 0..self.options.parallel_jobs {
+                scope.spawn(|| self.run_jobs(&receiver, &queued_jobs, &finished_jobs));
+            }
+
+            for (connection_number, stream) in self.listener.incoming().enumerate() {
+                if should_stop().is_err() {
+                    break;
+                }
+
+                if let Ok(stream) = stream {
+                    if let Some(stream) = self.register_connection(&connections, connection_number, stream) {
+                        let sender = sender.clone();
+                        let (connections, queued_jobs) = (&connections, &queued_jobs);
+                        let options = (self.options.complete, self.options.timeout, self.options.strict, self.options.deletion_policy);
+                        scope.spawn(move || {
+                            Self::handle_connection(stream, sender, queued_jobs, options);
+                            if let Ok(mut connections) = connections.lock() {
+                                connections.remove(&connection_number);
+                            }
+                        });
+                    }
+                }
+            }
+
+            //Connections that have been registered after the signal was handled are shut down as well
+            Self::shutdown_connections(&connections);
+            signals_handle.close();
+        });
+
+        _ = fs::remove_file(&self.socket_path);
+        resume_all(); //The interruption only applies to this server
+        Ok(())
+    }
+
+    /// Registers the stream of a connection, such that it is shut down once the server is stopped, and returns it.
+    /// If the server is stopped or max_connections are already handled, the connection is rejected and None is returned.
+    fn register_connection(&self, connections: &Mutex<HashMap<usize, UnixStream>>, connection_number: usize, stream: UnixStream) -> Option<UnixStream> {
+        let mut connections = connections.lock().ok()?;
+
+        //Checked while holding the lock, such that the stream is either shut down by the signal handler or not registered at all
+        if should_stop().is_err() {
+            return None;
+        }
+
+        if connections.len() >= self.options.max_connections {
+            let response = Response { id: Value::Null, verdict: Verdict::Error.to_string(), details: format!("The server handles at most {} connections at the same time", self.options.max_connections), time: 0.0, cached: false };
+            Self::send_response(&Mutex::new(stream), &response);
+            return None;
+        }
+
+        connections.insert(connection_number, stream.try_clone().ok()?);
+        Some(stream)
+    }
+
+    /// Shuts down the streams of all registered connections, such that their threads stop waiting for requests.
+    fn shutdown_connections(connections: &Mutex<HashMap<usize, UnixStream>>) {
+        if let Ok(connections) = connections.lock() {
+            for stream in connections.values() {
+                _ = stream.shutdown(Shutdown::Both);
+            }
+        }
+    }
+
+    /// Verifies queued jobs until the server is stopped.
+    fn run_jobs(&self, receiver: &Mutex<Receiver<QueuedJob>>, queued_jobs: &AtomicUsize, finished_jobs: &AtomicUsize) {
+        while should_stop().is_ok() {
+            let queued_job = match receiver.lock() {
+                Ok(receiver) => receiver.recv_timeout(Duration::from_millis(100)),
+                Err(_) => return
+            };
+
+            let queued_job = match queued_job {
+                Ok(queued_job) => queued_job,
+                Err(RecvTimeoutError::Timeout) => continue,
+                Err(RecvTimeoutError::Disconnected) => return
+            };
+            queued_jobs.fetch_sub(1, Ordering::AcqRel);
+
+            let start_time = Instant::now();
+            let response = match self.cache.get(&queued_job.job.framework, queued_job.job.format, &queued_job.job.description, queued_job.job.strict) {
+                Ok((framework, cached)) => {
+                    let supervisor = Arc::new(Supervisor::new(self.options.number_of_threads));
+                    verify_job(&supervisor, queued_job.job, Some(framework), queued_job.complete, queued_job.timeout);
+                    let (verdict, details) = Verdict::from_supervisor(&supervisor);
+                    Response { id: queued_job.id, verdict: verdict.to_string(), details, time: start_time.elapsed().as_secs_f64(), cached }
+                },
+                Err(error) => {
+                    let verdict = if should_stop().is_err() { Verdict::Error } else { Verdict::Invalid };
+                    Response { id: queued_job.id, verdict: verdict.to_string(), details: error.to_string(), time: start_time.elapsed().as_secs_f64(), cached: false }
+                }
+            };
+
+            Self::send_response(&queued_job.connection, &response);
+            finished_jobs.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+
+    /// Reads the requests of a connection line by line and queues the respective jobs. Malformed requests are answered immediately.
+    fn handle_connection(stream: UnixStream, sender: Sender<QueuedJob>, queued_jobs: &AtomicUsize, defaults: RequestDefaults) {
+        let reader = match stream.try_clone() {
+            Ok(reader) => BufReader::new(reader),
+            Err(_) => return
+        };
+        let connection = Arc::new(Mutex::new(stream));
+
+        for (line_number, line) in reader.lines().enumerate() {
+            let line = match line {
+                Ok(line) => line,
+                Err(_) => return
+            };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            //Requests without id are identified by their line number
+            match Self::parse_request(&line, Value::from(line_number + 1), defaults, &connection) {
+                Ok(queued_job) => {
+                    queued_jobs.fetch_add(1, Ordering::AcqRel);
+                    if sender.send(queued_job).is_err() {
+                        return;
+                    }
+                },
+                Err(response) => Self::send_response(&connection, &response)
+            }
+        }
+    }
+
+    /// Parses a request into a job, whose result is sent to the connection, or returns the response for a malformed request.
+    fn parse_request(line: &str, default_id: Value, (complete, timeout, strict, deletion_policy): RequestDefaults, connection: &Arc<Mutex<UnixStream>>) -> Result<QueuedJob, Response> {
+        let invalid = |id: Value, details: String| Response { id, verdict: Verdict::Invalid.to_string(), details, time: 0.0, cached: false };
+        let request = match serde_json::from_str::<Request>(line) {
+            Ok(request) => request,
+            Err(err) => return Err(invalid(default_id, format!("The request '{}' is malformed: {}", line, err)))
+        };
+
+        let id = request.id.unwrap_or(default_id);
+        let get_source = |path: Option<PathBuf>, content: Option<String>, name: &str| match (path, content) {
+            (Some(_), Some(_)) => Err(invalid(id.clone(), format!("The request '{}' provides both '{}' and '{}_content'", line, name, name))),
+            (Some(path), None) => Ok(Some(Source::Path(path))),
+            (None, Some(content)) => Ok(Some(Source::from(content))),
+            (None, None) => Ok(None)
+        };
+
+        let framework = get_source(request.instance, request.instance_content, "instance")?;
+        let description = get_source(request.description, request.description_content, "description")?;
+        let required_arguments = get_source(request.required, request.required_content, "required")?;
+        let proof = get_source(request.proof, request.proof_content, "proof")?;
+
+        let (framework, proof) = match (framework, proof) {
+            (Some(framework), Some(proof)) => (framework, proof),
+            _ => return Err(invalid(id, format!("The request '{}' must provide the instance and the proof", line)))
+        };
+
+        let semantics = match Semantics::from_str(&request.semantics, true) {
+            Ok(semantics) => semantics,
+            Err(_) => return Err(invalid(id, format!("The request '{}' references the semantics '{}' that is invalid", line, request.semantics)))
+        };
+
+        let format = match &request.format {
+            Some(name) => match FrameworkFormat::from_str(name, true) {
+                Ok(format) => Some(format),
+                Err(_) => return Err(invalid(id, format!("The request '{}' references the format '{}' that is invalid", line, name)))
+            },
+            None => None
+        };
+
+        let complete = request.complete.unwrap_or(complete);
+        let timeout = match request.timeout {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => timeout
+        };
+
+        let deletion_policy = match &request.deletion_policy {
+            Some(name) => match DeletionPolicy::from_str(name, true) {
+                Ok(deletion_policy) => deletion_policy,
+                Err(_) => return Err(invalid(id, format!("The request '{}' references the deletion policy '{}' that is invalid", line, name)))
+            },
+            None => deletion_policy
+        };
+
+        let strict = request.strict.unwrap_or(strict);
+        let clause_ids = request.clause_ids.unwrap_or(false);
+        let job = VerificationJob { framework, format, description, required_arguments, proof, semantics, strict, clause_ids, deletion_policy, extension_constraint: None };
+        Ok(QueuedJob { id, job, complete, timeout, connection: Arc::clone(connection) })
+    }
+
+    /// Sends the response as a single line. Errors are ignored, as the client may have disconnected.
+    fn send_response(connection: &Mutex<UnixStream>, response: &Response) {
+        if let (Ok(mut stream), Ok(line)) = (connection.lock(), serde_json::to_string(response)) {
+            _ = writeln!(stream, "{}", line);
+        }
+    }
+}
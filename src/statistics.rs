@@ -0,0 +1,120 @@
+use std::fmt::{Display, Formatter};
+use crate::clause_base::ClauseBase;
+use crate::framework_base::FrameworkBase;
+use crate::instance_base::InstanceBase;
+use crate::semantics::VerifierType;
+
+/// Statistics about the sizes of clauses.
+#[derive(Default)]
+pub struct SizeStatistics {
+    /// The number of clauses.
+    pub count: usize,
+    /// The number of clauses with a single member.
+    pub units: usize,
+    /// The number of members of the largest clause.
+    pub max_size: usize,
+    /// The total number of members of all clauses.
+    pub total_size: usize
+}
+
+impl SizeStatistics {
+
+    /// Creates the statistics for the provided clauses.
+    fn new<'a>(clauses: impl Iterator<Item = &'a ClauseBase>) -> SizeStatistics {
+        let mut statistics = SizeStatistics::default();
+        for clause in clauses {
+            let size = clause.get_number_of_members();
+            statistics.count += 1;
+            statistics.total_size += size;
+            statistics.max_size = statistics.max_size.max(size);
+            if size == 1 {
+                statistics.units += 1;
+            }
+        }
+        statistics
+    }
+
+    /// Returns the average number of members of the clauses.
+    pub fn get_average_size(&self) -> f64 {
+        if self.count == 0 { 0.0 } else { self.total_size as f64 / self.count as f64 }
+    }
+}
+
+/// Statistics about a framework.
+pub struct FrameworkStatistics {
+    /// The number of arguments.
+    pub arguments: usize,
+    /// The number of named arguments.
+    pub named_arguments: usize,
+    /// The sizes of the attacks.
+    pub attacks: SizeStatistics
+}
+
+impl FrameworkStatistics {
+
+    /// Creates the statistics for the provided framework.
+    pub fn new(framework: &FrameworkBase) -> FrameworkStatistics {
+        FrameworkStatistics {
+            arguments: framework.arguments.len(),
+            named_arguments: framework.argument_names.len(),
+            attacks: SizeStatistics::new(framework.attacks.iter())
+        }
+    }
+}
+
+impl Display for FrameworkStatistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Arguments: {}", self.arguments)?;
+        writeln!(f, "Named arguments: {}", self.named_arguments)?;
+        writeln!(f, "Attacks: {}", self.attacks.count)?;
+        writeln!(f, "Unit attacks: {}", self.attacks.units)?;
+        writeln!(f, "Maximal attack size: {}", self.attacks.max_size)?;
+        write!(f, "Average attack size: {:.2}", self.attacks.get_average_size())
+    }
+}
+
+/// Statistics about a proof.
+pub struct ProofStatistics {
+    /// The number of required arguments.
+    pub required_arguments: usize,
+    /// The sizes of the proof clauses, excluding the empty clause.
+    pub clauses: SizeStatistics,
+    /// The number of clauses that are verified by RUP.
+    pub rup_clauses: usize,
+    /// The number of clauses that are verified by the semantics specific verifiers.
+    pub implicit_clauses: usize,
+    /// The number of deleted clauses.
+    pub deletions: usize,
+    /// The number of extension variables introduced by the proof.
+    pub extension_variables: usize
+}
+
+impl ProofStatistics {
+
+    /// Creates the statistics for the proof of the provided instance.
+    pub fn new(instance: &InstanceBase) -> ProofStatistics {
+        let rup_clauses = instance.proof_clauses.iter().filter(|clause| matches!(clause.get_verifier(), VerifierType::RUP)).count();
+        ProofStatistics {
+            required_arguments: instance.required_arguments.len(),
+            clauses: SizeStatistics::new(instance.proof_clauses.iter()),
+            rup_clauses,
+            implicit_clauses: instance.proof_clauses.len() - rup_clauses,
+            deletions: instance.clause_states.iter().filter(|state| state.deleted_at().is_some()).count(),
+            extension_variables: instance.number_of_variables - instance.framework.arguments.len()
+        }
+    }
+}
+
+impl Display for ProofStatistics {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Required arguments: {}", self.required_arguments)?;
+        writeln!(f, "Proof clauses: {}", self.clauses.count)?;
+        writeln!(f, "RUP clauses: {}", self.rup_clauses)?;
+        writeln!(f, "Implicit clauses: {}", self.implicit_clauses)?;
+        writeln!(f, "Unit clauses: {}", self.clauses.units)?;
+        writeln!(f, "Deleted clauses: {}", self.deletions)?;
+        writeln!(f, "Extension variables: {}", self.extension_variables)?;
+        writeln!(f, "Maximal clause size: {}", self.clauses.max_size)?;
+        write!(f, "Average clause size: {:.2}", self.clauses.get_average_size())
+    }
+}
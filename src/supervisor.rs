@@ -1,18 +1,21 @@
-use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
-use std::sync::{Mutex};
+use std::sync::{Arc, Condvar, Mutex};
 use std::cmp::min;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::thread;
+use std::time::Duration;
 use num_enum::IntoPrimitive;
 use num_enum::TryFromPrimitive;
 use once_cell::sync::OnceCell;
-use signal_hook::consts::SIGUSR1;
+use crate::framework_base::FrameworkBase;
+use crate::parsers::parse_error::ParseError;
 use crate::instance_base::InstanceBase;
-use crate::semantics::Semantics;
-use crate::{INSTANCE, should_stop};
+use crate::{should_stop, VerificationJob};
 use crate::worker::{Work, Worker};
 
 
-#[derive(Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
+#[derive(Debug, Eq, PartialEq, IntoPrimitive, TryFromPrimitive)]
 #[repr(u8)]
 pub enum SupervisorState {
     NotStarted,
@@ -20,6 +23,8 @@ pub enum SupervisorState {
     ParsingFailed,
     RequiredArgumentInconsistent,
     Finished,
+    TimedOut,
+    Interrupted,
     UnexpectedError,
     Unknown
 }
@@ -43,11 +48,37 @@ impl SupervisorData {
     }
 }
 
+/// A snapshot of the progress of a running verification.
+pub struct Progress {
+    /// The number of clauses that are queued for verification.
+    pub queued: usize,
+    /// The number of proof clauses that have been verified successfully.
+    pub verified: usize,
+    /// The number of proof clauses that have been found to be used so far.
+    pub used: usize,
+    /// The number of workers that are currently verifying clauses.
+    pub active_workers: usize,
+    /// The number of workers that are currently stalled.
+    pub stalled_workers: usize
+}
+
+impl Display for Progress {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Progress: {} queued, {} verified, {} used, {} active workers, {} stalled workers", self.queued, self.verified, self.used, self.active_workers, self.stalled_workers)
+    }
+}
+
 pub struct Supervisor {
+    /// The number of worker threads to use.
+    number_of_threads: u16,
     /// The state of the supervisor.
     state: Box<AtomicU8>,
     /// The workers that have been spawned.
     workers: OnceCell<Vec<Worker>>,
+    /// The instance that is verified.
+    instance: OnceCell<InstanceBase>,
+    /// Whether or not the verification has terminated, used to wait for the termination.
+    terminated: (Mutex<bool>, Condvar),
     /// The data the supervisor is working on.
     data: Mutex<Option<SupervisorData>>,
     /// Whether or not the proof was verified successfully.
@@ -58,8 +89,14 @@ pub struct Supervisor {
     failed_empty_clause_verification: Box<AtomicBool>,
     /// The index of the first clause that needs to be verified.
     first_clause_index_to_verify: Box<AtomicUsize>,
-    /// The error message that occurred during instance parsing.
-    parsing_error_message: OnceCell<String>
+    /// The number of proof clauses that have been verified successfully.
+    verified_clause_counter: Box<AtomicUsize>,
+    /// The number of proof clauses that have been found to be used.
+    used_clause_counter: Box<AtomicUsize>,
+    /// The error that occurred during instance parsing.
+    parsing_error: OnceCell<ParseError>,
+    /// The deleted clauses that would have propagated an argument, mapped to the smallest index of a clause whose verification they would have been used for.
+    deleted_reasons: Mutex<BTreeMap<usize, usize>>
 }
 
 impl Supervisor {
@@ -79,39 +116,69 @@ impl Supervisor {
                     }
                 }
             },
-            SupervisorState::NotStarted | SupervisorState::Working | SupervisorState::ParsingFailed | SupervisorState::UnexpectedError | SupervisorState::Unknown => None
+            SupervisorState::NotStarted | SupervisorState::Working | SupervisorState::ParsingFailed | SupervisorState::TimedOut | SupervisorState::Interrupted | SupervisorState::UnexpectedError | SupervisorState::Unknown => None
         }
     }
 
-    pub fn new() -> Supervisor {
+    pub fn new(number_of_threads: u16) -> Supervisor {
         Supervisor {
+            number_of_threads,
             state: Box::new(AtomicU8::new(SupervisorState::NotStarted.into())),
             workers: OnceCell::new(),
+            instance: OnceCell::new(),
+            terminated: (Mutex::new(false), Condvar::new()),
             data: Mutex::new(None),
             verification_successful: Box::new(AtomicBool::new(true)),
             failed_clause_index: Box::new(AtomicUsize::new(0)),
             failed_empty_clause_verification: Box::new(AtomicBool::new(false)),
             first_clause_index_to_verify: Box::new(AtomicUsize::new(0)),
-            parsing_error_message: OnceCell::new()
+            verified_clause_counter: Box::new(AtomicUsize::new(0)),
+            used_clause_counter: Box::new(AtomicUsize::new(0)),
+            parsing_error: OnceCell::new(),
+            deleted_reasons: Mutex::new(BTreeMap::new())
         }
     }
 
-    /// Starts the supervisor in a new thread. Parses the instance, description, proof and required arguments and delegates the work of checking the proof over the provided number of threads. Stops scheduling new work once DO_WORK in main is set to false.
-    pub fn start(&'static self, number_of_threads: u16, framework_path: PathBuf, description_path: Option<PathBuf>, required_arguments_path: Option<PathBuf>, proof_path: PathBuf, semantics: Semantics, complete: bool) {
+    /// Starts the supervisor in a new thread. Parses the instance, description, proof and required arguments and delegates the work of checking the proof over the provided number of threads. Stops scheduling new work once the verification has been stopped or stop_all has been called.
+    ///
+    /// If a framework is provided, it is used instead of parsing the instance and description files of the job.
+    pub fn start(self: &Arc<Self>, job: VerificationJob, framework: Option<Arc<FrameworkBase>>, complete: bool) {
+        let supervisor = Arc::clone(self);
+        thread::spawn(move || supervisor.run(job, framework, complete));
+    }
+
+    /// Starts the supervisor in a new thread like start, but verifies the provided instance, which has already been parsed.
+    pub fn start_with_instance(self: &Arc<Self>, instance: InstanceBase, complete: bool) {
+        let supervisor = Arc::clone(self);
+        thread::spawn(move || supervisor.run_instance(instance, complete));
+    }
+
+    fn run(self: &Arc<Self>, job: VerificationJob, framework: Option<Arc<FrameworkBase>>, complete: bool) {
 
         // Start by parsing the instance
-        let instance = InstanceBase::new(&framework_path, &description_path, &required_arguments_path, &proof_path, &semantics);
-        if let Err(message) = instance {
-            if self.parsing_error_message.set(message).is_err() {
-                self.set_state_and_exit(SupervisorState::UnexpectedError);
-            }
-            else {
-                self.set_state_and_exit(SupervisorState::ParsingFailed);
+        match InstanceBase::from_job(&job, framework) {
+            Ok(instance) => self.run_instance(instance, complete),
+            Err(error) => {
+                if should_stop().is_err() {
+                    self.set_state_and_exit(SupervisorState::Interrupted);
+                }
+                else if self.parsing_error.set(error).is_err() {
+                    self.set_state_and_exit(SupervisorState::UnexpectedError);
+                }
+                else {
+                    self.set_state_and_exit(SupervisorState::ParsingFailed);
+                }
             }
-            return;
         }
+    }
+
+    fn run_instance(self: &Arc<Self>, instance: InstanceBase, complete: bool) {
 
-        let instance = instance.unwrap();
+        if self.instance.set(instance).is_err() {
+            self.set_state_and_exit(SupervisorState::UnexpectedError);
+            return;
+        }
+        let instance = self.get_instance().unwrap();
 
         if !instance.is_required_arguments_consistent() {
             self.verification_successful.store(true, Ordering::Release);
@@ -119,7 +186,7 @@ impl Supervisor {
             return;
         }
 
-        let number_of_clauses = instance.clauses.len();
+        let number_of_clauses = instance.get_number_of_clauses();
         self.first_clause_index_to_verify.store(instance.proof_start, Ordering::Release);
 
         //This should not cause an error, as no one else should have used the lock yet
@@ -127,34 +194,30 @@ impl Supervisor {
         {
             assert!(data.is_none());
 
-            let number_of_workers = min(number_of_threads as usize,number_of_clauses) as u16; //Cast is fine, as we take the minimum and thus are never larger then u16
+            let number_of_workers = min(self.number_of_threads as usize, number_of_clauses + 1) as u16; //Cast is fine, as we take the minimum and thus are never larger then u16. The empty clause needs to be verified as well.
             *data = Some(SupervisorData::new(number_of_workers));
 
             if complete {
                 let data = data.as_mut().unwrap();
-                for clause in &instance.clauses[instance.proof_start..] {
-                    clause.set_used();
+                for clause in &instance.proof_clauses {
+                    instance.clause_states[clause.get_index()].set_used();
                     data.to_check.push(Some(clause.get_index()));
                 }
+                self.used_clause_counter.store(number_of_clauses - instance.proof_start, Ordering::Release);
             }
 
-            if INSTANCE.set(Box::new(instance)).is_err() {
-                self.set_state_and_exit(SupervisorState::UnexpectedError);
-                return;
+            // Setup. The state needs to be set before the workers are spawned, as they would otherwise terminate immediately.
+            if self.state.compare_exchange(SupervisorState::NotStarted.into(), SupervisorState::Working.into(), Ordering::AcqRel, Ordering::Relaxed).is_err() {
+                return; //The verification has been stopped in the meantime
             }
-
-            // Setup
             let mut workers = Vec::new();
             for i in 0..number_of_workers as usize {
-                let worker = Worker::new(i);
+                let worker = Worker::new(i, Arc::clone(self));
                 workers.push(worker);
             }
             if self.workers.set(workers).is_err() {
                 self.set_state_and_exit(SupervisorState::UnexpectedError);
-                return;
             }
-
-            self.set_state(SupervisorState::Working);
         }
         else
         {
@@ -162,6 +225,36 @@ impl Supervisor {
         }
     }
 
+    /// Waits until the verification has terminated or the timeout has been reached. Returns whether or not the verification has terminated.
+    pub fn wait(&self, timeout: Option<Duration>) -> bool {
+        let (mutex, cvar) = &self.terminated;
+        let guard = mutex.lock().unwrap();
+        match timeout {
+            None => *cvar.wait_while(guard, |terminated| !*terminated).unwrap(),
+            Some(timeout) => *cvar.wait_timeout_while(guard, timeout, |terminated| !*terminated).unwrap().0
+        }
+    }
+
+    /// Stops the verification with the provided state if it has not terminated yet.
+    pub fn stop(&self, state: SupervisorState) {
+        self.set_state_and_exit(state);
+    }
+
+    /// Returns whether or not the verification is still running, such that long checks can be abandoned once it has been stopped.
+    #[inline]
+    pub fn is_working(&self) -> bool {
+        self.get_state() == SupervisorState::Working
+    }
+
+    /// Waits until the threads of the workers have terminated, which they do once the verification has been stopped or finished.
+    pub fn join_workers(&self) {
+        if let Some(workers) = self.workers.get() {
+            for worker in workers {
+                worker.join();
+            }
+        }
+    }
+
     pub fn get_state(&self) -> SupervisorState {
         match SupervisorState::try_from(self.state.load(Ordering::Acquire)) {
             Ok(state) => state,
@@ -171,23 +264,33 @@ impl Supervisor {
 
     fn set_finished(&self) {
         _ = self.state.compare_exchange(SupervisorState::Working.into(), SupervisorState::Finished.into(), Ordering::AcqRel, Ordering::Relaxed); //Nothing to be done if failed, as we were no longer working then anyway.
-        if let Err(_) = signal_hook::low_level::raise(SIGUSR1) {
-            println!("Failed to raise signal during verification.");
-            quit::with_code(1);
-        };
+        self.set_terminated();
     }
 
-    fn set_state(&self, state: SupervisorState)
-    {
-        self.state.store(state.into(), Ordering::Release);
+    /// Sets the state if the verification has not terminated yet.
+    fn set_state_and_exit(&self, state: SupervisorState) {
+        let state : u8 = state.into();
+        let not_started : u8 = SupervisorState::NotStarted.into();
+        let working : u8 = SupervisorState::Working.into();
+        _ = self.state.fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+            if current == not_started || current == working { Some(state) } else { None }
+        });
+        self.set_terminated();
     }
 
-    fn set_state_and_exit(&self, state: SupervisorState) {
-        self.set_state(state);
-        if let Err(_) = signal_hook::low_level::raise(SIGUSR1) {
-            println!("Failed to raise signal during verification.");
-            quit::with_code(1);
-        };
+    /// Wakes up the stalled workers, so that they can terminate, and notifies those waiting for the termination.
+    fn set_terminated(&self) {
+        if let Some(workers) = self.workers.get() {
+            for worker in workers {
+                worker.wake_up();
+            }
+        }
+
+        let (mutex, cvar) = &self.terminated;
+        if let Ok(mut terminated) = mutex.lock() {
+            *terminated = true;
+            cvar.notify_all();
+        }
     }
 
     pub fn worker_error_occurred(&self) {
@@ -195,7 +298,8 @@ impl Supervisor {
     }
 
     pub fn get_work(&self, worker_index: usize) -> Work {
-        if let Err(_) = should_stop() {
+        if should_stop().is_err() {
+            self.set_state_and_exit(SupervisorState::Interrupted);
             Work::Finished
         }
         else {
@@ -205,11 +309,11 @@ impl Supervisor {
             }
 
             if let Ok(mut guard) = self.data.lock() {
-                let ref mut data = *guard.as_mut().unwrap();
+                let data = &mut *guard.as_mut().unwrap();
 
                 //We first see if we can schedule any clauses
                 if let Some(index) = data.to_check.pop() {
-                    Work::Work(index)
+                    Work::Verify(index)
                 }
                 else {
                     // We do not know of any clauses that need verification yet, thus we tell the worker to stall.
@@ -242,7 +346,12 @@ impl Supervisor {
     }
 
     pub fn worker_finished(&self, clause_index: Option<usize>, result: bool) {
-        if !result {
+        if result {
+            if clause_index.is_some() {
+                self.verified_clause_counter.fetch_add(1, Ordering::AcqRel);
+            }
+        }
+        else {
             // Clause failed to verify
             self.verification_successful.store(false, Ordering::Release);
             if let Some(index) = clause_index {
@@ -256,14 +365,32 @@ impl Supervisor {
         }
     }
 
+    /// Records that the deleted clause would have propagated an argument during the verification of the clause with the provided index.
+    pub fn add_deleted_reason(&self, clause_index: usize, verification_index: usize) {
+        if let Ok(mut deleted_reasons) = self.deleted_reasons.lock() {
+            let entry = deleted_reasons.entry(clause_index).or_insert(verification_index);
+            *entry = min(*entry, verification_index);
+        }
+    }
+
+    /// Returns the deleted clauses that would have propagated an argument, together with the smallest index of a clause whose verification they would have been used for, ordered by their indices.
+    pub fn get_deleted_reasons(&self) -> Vec<(usize, usize)> {
+        match self.deleted_reasons.lock() {
+            Ok(deleted_reasons) => deleted_reasons.iter().map(|(clause_index, verification_index)| (*clause_index, *verification_index)).collect(),
+            Err(_) => Vec::new()
+        }
+    }
+
     pub fn add_clause_to_check(&self, clause_index: usize) {
 
         if clause_index < self.first_clause_index_to_verify.load(Ordering::Acquire) {
             return; //We dont verify clauses that are not part of the proof.
         }
 
+        self.used_clause_counter.fetch_add(1, Ordering::AcqRel);
+
         if let Ok(mut guard) = self.data.lock() {
-            let ref mut data = *guard.as_mut().unwrap();
+            let data = &mut *guard.as_mut().unwrap();
             data.to_check.push(Some(clause_index));
             //Wake up another worker if available to schedule it
             if let Some(worker_id) = data.stalled_workers.pop()
@@ -277,10 +404,30 @@ impl Supervisor {
     }
 
     pub fn get_workers(&self) -> &Vec<Worker> {
-        &self.workers.get().unwrap()
+        self.workers.get().unwrap()
+    }
+
+    pub fn get_parsing_error(&self) -> &ParseError {
+        self.parsing_error.get().unwrap()
+    }
+
+    /// Returns the instance or None if it has not been parsed (successfully).
+    pub fn get_instance(&self) -> Option<&InstanceBase> {
+        self.instance.get()
     }
 
-    pub fn get_parsing_error_message(&self) -> &String {
-        self.parsing_error_message.get().unwrap()
+    /// Returns a snapshot of the current progress or None if the verification has not been started yet.
+    pub fn get_progress(&self) -> Option<Progress> {
+        let guard = self.data.lock().ok()?;
+        let data = guard.as_ref()?;
+        let stalled_workers = data.stalled_workers.len();
+
+        Some(Progress {
+            queued: data.to_check.len(),
+            verified: self.verified_clause_counter.load(Ordering::Acquire),
+            used: self.used_clause_counter.load(Ordering::Acquire),
+            active_workers: data.deployed_worker_counter as usize - stalled_workers,
+            stalled_workers
+        })
     }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod rup_verifier;
 pub mod stability_verifier;
 pub mod admissibility_verifier;
+pub mod redundancy_verifier;
 mod verification_helpers;
\ No newline at end of file
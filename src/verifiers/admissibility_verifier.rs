@@ -5,7 +5,10 @@ use crate::verifiers::verification_helpers::contains_clause_witnesses;
 pub fn admissibility_verify(index: usize, instance: &InstanceView, attack_index: Option<usize>) -> bool {
 
     let clause_members = instance.get_clause_members(index);
-    let (admissibility_argument_index, admissibility_argument_sign) = clause_members.first().unwrap();
+    let (admissibility_argument_index, admissibility_argument_sign) = match clause_members.first() {
+        Some(member) => member,
+        None => return false //Implicit clauses cannot be empty
+    };
 
     if *admissibility_argument_sign {
         return false;
@@ -14,8 +17,8 @@ pub fn admissibility_verify(index: usize, instance: &InstanceView, attack_index:
     let attacks = instance.get_attacked_by(*admissibility_argument_index);
     let clause_members : HashSet<usize> = clause_members.iter().map(|(index, _) | *index).collect();
 
-    return if let Some(attack_index) = attack_index {
-        if !attacks.contains(&attack_index) {
+    if let Some(attack_index) = attack_index {
+        if !attacks.contains(&attack_index) || instance.is_attack_removed(attack_index) {
             return false;
         }
 
@@ -23,7 +26,7 @@ pub fn admissibility_verify(index: usize, instance: &InstanceView, attack_index:
     }
     else {
 
-        for attack_index in attacks {
+        for attack_index in attacks.iter().filter(|attack_index| !instance.is_attack_removed(**attack_index)) {
             if admissibility_verify_for_attack(&clause_members, *attack_index, instance) {
                 instance.set_clause_used(*attack_index);
                 return true;
@@ -44,10 +47,10 @@ fn admissibility_verify_for_attack(clause_members: &HashSet<usize>, attack_index
 
     for (attack_member_index, _) in &instance.get_clause_members(attack_index)[1..] {
         let attacked_by = instance.get_attacked_by(*attack_member_index);
-        for attack in attacked_by {
+        for attack in attacked_by.iter().filter(|attack| !instance.is_attack_removed(**attack)) {
             instance.set_clause_used(*attack);
         }
     }
 
-    return true;
+    true
 }
\ No newline at end of file
@@ -0,0 +1,110 @@
+use std::collections::{BTreeSet, HashMap};
+use crate::instance_view::InstanceView;
+use crate::supervisor::Supervisor;
+use crate::verifiers::rup_verifier::rup_verify_with;
+
+/// Verifies the clause with the provided index as redundant by propagation (PR) or substitution (SR) with its witness.
+/// The witness maps every preceding active clause, every required argument and the clause itself onto a clause, which needs to be verifiable by reverse unit propagation together with the clause, unless it is satisfied by the witness.
+/// Clauses that are not changed by the witness and attacks that are mapped onto active attacks are implied trivially. The latter requires that the permutation of the witness is an automorphism of the attack relation, which is checked first.
+pub fn redundancy_verify(index: usize, instance: &mut InstanceView, supervisor: &Supervisor) -> bool {
+    let witness = instance.get_witness(index).unwrap();
+    let assignment : HashMap<usize, bool> = witness.get_assignment().iter().copied().collect();
+    let permutation : HashMap<usize, usize> = witness.get_permutation().iter().copied().collect();
+
+    let attack_images = match map_attacks(&permutation, instance) {
+        Some(attack_images) => attack_images,
+        None => return false
+    };
+
+    //Only clauses that contain arguments which are assigned or permuted are changed by the witness
+    let mut candidates = BTreeSet::new();
+    for argument in assignment.keys().chain(permutation.keys()) {
+        candidates.extend(instance.get_active_clauses_containing(*argument, true, index));
+        candidates.extend(instance.get_active_clauses_containing(*argument, false, index));
+    }
+
+    for candidate in candidates {
+        if !supervisor.is_working() {
+            return false; //The verification has been stopped
+        }
+        let members = instance.get_clause_members(candidate);
+        if members.iter().all(|(argument, _)| !assignment.contains_key(argument)) && attack_images.get(&candidate).is_some_and(|image| instance.clause_is_not_deleted_for(*image, index)) {
+            continue; //The attack is mapped onto an active attack
+        }
+        match substitute(members, &assignment, &permutation) {
+            Some(mapped) if !has_same_members(&mapped, members) && !rup_verify_with(index, &mapped, instance, supervisor) => return false,
+            _ => {}
+        }
+    }
+
+    //The required arguments are unit clauses of the formula, which are implied trivially if they are mapped onto required arguments. Those changed by the witness are premises of the clause
+    let required_arguments = instance.get_required_arguments().clone();
+    for required_argument in &required_arguments {
+        if !supervisor.is_working() {
+            return false; //The verification has been stopped
+        }
+        if assignment.contains_key(&required_argument.0) || permutation.contains_key(&required_argument.0) {
+            instance.set_required_argument_used(required_argument.0);
+        }
+        match substitute(&[*required_argument], &assignment, &permutation) {
+            Some(mapped) if (mapped.len() != 1 || !required_arguments.contains(&mapped[0])) && !rup_verify_with(index, &mapped, instance, supervisor) => return false,
+            _ => {}
+        }
+    }
+
+    //The clause itself is not part of the formula yet and thus needs to be verified even if it is not changed
+    match substitute(instance.get_clause_members(index), &assignment, &permutation) {
+        Some(mapped) => rup_verify_with(index, &mapped, instance, supervisor),
+        None => true
+    }
+}
+
+/// Returns the members of the clause that is obtained by applying the assignment and permutation to the provided members, or None if the clause is satisfied by the assignment.
+fn substitute(members: &[(usize, bool)], assignment: &HashMap<usize, bool>, permutation: &HashMap<usize, usize>) -> Option<Vec<(usize, bool)>> {
+    let mut mapped = Vec::with_capacity(members.len());
+    for (argument, sign) in members {
+        match assignment.get(argument) {
+            Some(value) if value == sign => return None,
+            Some(_) => {}, //The member is falsified and thus removed
+            None => {
+                let member = (*permutation.get(argument).unwrap_or(argument), *sign);
+                if !mapped.contains(&member) {
+                    mapped.push(member);
+                }
+            }
+        }
+    }
+    Some(mapped)
+}
+
+/// Returns whether or not both clauses contain the same members, regardless of their order.
+fn has_same_members(first: &[(usize, bool)], second: &[(usize, bool)]) -> bool {
+    first.iter().all(|member| second.contains(member)) && second.iter().all(|member| first.contains(member))
+}
+
+/// Returns the images of the attacks that contain permuted arguments, which are marked as used, or None if the permutation is not an automorphism of the attack relation, i.e. one of them is not mapped onto an attack.
+fn map_attacks(permutation: &HashMap<usize, usize>, instance: &InstanceView) -> Option<HashMap<usize, usize>> {
+    let map = |argument: &usize| *permutation.get(argument).unwrap_or(argument);
+    let mut attack_images = HashMap::new();
+    for argument in permutation.keys() {
+        for attack_index in instance.get_attacks_containing(*argument) {
+            if attack_images.contains_key(&attack_index) || instance.is_attack_removed(attack_index) {
+                continue;
+            }
+
+            let members = instance.get_clause_members(attack_index);
+            let mut attackers : Vec<usize> = members[1..].iter().map(|(attacker, _)| map(attacker)).collect();
+            attackers.sort_unstable();
+            attackers.dedup();
+            let image = instance.get_attacked_by(map(&members[0].0)).iter().copied().filter(|image| !instance.is_attack_removed(*image)).find(|image| {
+                let mut image_attackers : Vec<usize> = instance.get_clause_members(*image)[1..].iter().map(|(attacker, _)| *attacker).collect();
+                image_attackers.sort_unstable();
+                image_attackers.dedup();
+                image_attackers == attackers
+            })?;
+            attack_images.insert(attack_index, image);
+            instance.set_clause_used(attack_index);
+        }
+    }
+    Some(attack_images)
+}
@@ -1,30 +1,76 @@
 use std::collections::VecDeque;
 use crate::instance_view::InstanceView;
-use crate::SUPERVISOR;
-
-pub fn rup_verify(index: Option<usize>, instance: &mut InstanceView) -> bool {
-    let mut assignments_todo = VecDeque::new();
+use crate::supervisor::Supervisor;
+use crate::verifiers::redundancy_verifier::redundancy_verify;
 
+/// Verifies the clause with the provided index, or the empty clause if none is provided, by reverse unit propagation.
+/// If this fails, a clause with a witness is verified as redundant by propagation or substitution and, if the instance allows it, any other clause as resolution asymmetric tautology on its first member.
+pub fn rup_verify(index: Option<usize>, instance: &mut InstanceView, supervisor: &Supervisor) -> bool {
     //If we are not handling the empty clause, we start by assigning the argument of the clause we are working on
-    if let Some(index) = index {
-        for member in instance.get_clause_members(index) {
-            assignments_todo.push_back(*member);
+    let assignments_todo = match index {
+        Some(index) => instance.get_clause_members(index).iter().copied().collect(),
+        None => VecDeque::new()
+    };
+
+    let verification_index = if let Some(index) = index { index } else { instance.get_number_of_clauses() };
+    if propagate_to_conflict(assignments_todo, verification_index, instance, supervisor) {
+        return true;
+    }
+
+    match index {
+        Some(index) if instance.get_witness(index).is_some() => redundancy_verify(index, instance, supervisor),
+        Some(index) if instance.allows_rat(index) => rat_verify(index, instance, supervisor),
+        _ => false
+    }
+}
+
+/// Verifies the clause with the provided index as resolution asymmetric tautology on its first member, the pivot.
+/// Every resolvent with an active clause that contains the negated pivot needs to be verified by reverse unit propagation, for which the assignment is reset.
+fn rat_verify(index: usize, instance: &mut InstanceView, supervisor: &Supervisor) -> bool {
+    let (pivot_index, pivot_sign) = instance.get_clause_members(index)[0];
+    let candidates = instance.get_active_clauses_containing(pivot_index, !pivot_sign, index);
+
+    for candidate in candidates {
+        if !supervisor.is_working() {
+            return false; //The verification has been stopped
+        }
+        let resolvent_members : Vec<(usize, bool)> = instance.get_clause_members(candidate).iter().copied().filter(|member| *member != (pivot_index, !pivot_sign)).collect();
+        if !rup_verify_with(index, &resolvent_members, instance, supervisor) {
+            return false;
         }
     }
 
-    let verification_index = if index.is_some() { index.unwrap() } else { instance.get_max_clause_index() + 1 };
+    true
+}
+
+/// Verifies the clause with the provided index extended by the provided members by reverse unit propagation, for which the assignment is reset.
+pub fn rup_verify_with(index: usize, additional_members: &[(usize, bool)], instance: &mut InstanceView, supervisor: &Supervisor) -> bool {
+    instance.reset(index);
+    let mut assignments_todo : VecDeque<(usize, bool)> = instance.get_clause_members(index).iter().copied().collect();
+    assignments_todo.extend(additional_members);
+    propagate_to_conflict(assignments_todo, index, instance, supervisor)
+}
+
+/// Assigns the provided arguments and propagates the clauses that precede the clause with the provided index. Returns whether or not a conflict has been found, where the propagation is abandoned once the verification has been stopped.
+/// The reasons of the propagated values are recorded, such that only the required arguments that lead to the conflict are marked as used.
+fn propagate_to_conflict(assignments_todo: VecDeque<(usize, bool)>, verification_index: usize, instance: &mut InstanceView, supervisor: &Supervisor) -> bool {
+    let mut assignments_todo : VecDeque<(usize, bool, Option<usize>)> = assignments_todo.into_iter().map(|(argument_index, value)| (argument_index, value, None)).collect();
     let mut propagated: bool;
     loop {
+        if !supervisor.is_working() {
+            return false; //The verification has been stopped, e.g. due to a timeout, thus the result does not matter
+        }
         propagated = false;
-        while let Some((argument_index, value)) = assignments_todo.pop_front() {
+        while let Some((argument_index, value, reason)) = assignments_todo.pop_front() {
             let current_value = instance.get_argument_value(argument_index);
             if let Some(current_value) = current_value {
                 if current_value != value {
+                    instance.set_conflict_reasons_used(argument_index, reason);
                     return true;
                 }
             }
             else {
-                instance.set_argument_value(argument_index, value);
+                instance.set_argument_value(argument_index, value, reason);
             }
         }
 
@@ -33,13 +79,19 @@ pub fn rup_verify(index: Option<usize>, instance: &mut InstanceView) -> bool {
                 let result = instance.check_clause_propagation(clause_index);
                 if let Some((argument_index, sign)) = result {
                     propagated = true;
-                    assignments_todo.push_back((argument_index, sign));
+                    assignments_todo.push_back((argument_index, sign, Some(clause_index)));
                     if instance.set_clause_used(clause_index) {
-                        SUPERVISOR.get().unwrap().add_clause_to_check(clause_index);
+                        supervisor.add_clause_to_check(clause_index);
                     }
                     break;
                 };
             }
+            else if clause_index < verification_index && instance.warns_on_deleted_reasons() && !instance.is_attack_removed(clause_index) {
+                //The deleted clause is not used, but reported if it would have propagated
+                if instance.check_clause_propagation(clause_index).is_some() {
+                    supervisor.add_deleted_reason(clause_index, verification_index);
+                }
+            }
         }
 
         if !propagated {
@@ -47,7 +99,5 @@ pub fn rup_verify(index: Option<usize>, instance: &mut InstanceView) -> bool {
         }
     }
 
-    return false;
+    false
 }
-
-
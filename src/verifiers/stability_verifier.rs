@@ -5,7 +5,10 @@ use crate::verifiers::verification_helpers::contains_clause_witnesses;
 pub fn stability_verify(index: usize, instance: &InstanceView) -> bool {
 
     let clause_members = instance.get_clause_members(index);
-    let (stability_argument_index, _) = clause_members.first().unwrap();
+    let (stability_argument_index, _) = match clause_members.first() {
+        Some(member) => member,
+        None => return false //Implicit clauses cannot be empty
+    };
     let attacked_by = instance.get_attacked_by(*stability_argument_index);
 
     //Implicit clauses for stability only contain positive literals
@@ -19,12 +22,12 @@ pub fn stability_verify(index: usize, instance: &InstanceView) -> bool {
     let result = contains_clause_witnesses(&clause_support, attacked_by, instance);
 
     if result {
-        for attack in attacked_by {
+        for attack in attacked_by.iter().filter(|attack| !instance.is_attack_removed(**attack)) {
             instance.set_clause_used(*attack);
         }
     }
 
-    return result;
+    result
 }
 
 
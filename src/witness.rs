@@ -0,0 +1,35 @@
+///The witness of a proof clause that is redundant by propagation (PR) or by substitution (SR), i.e. an assignment and a permutation of arguments.
+///The formula, which the witness maps onto, needs to be implied by the formula and the proof clause.
+#[derive(Clone)]
+pub struct Witness {
+    ///The arguments that are assigned by the witness with their values, starting with the first member of the clause.
+    assignment: Vec<(usize, bool)>,
+    ///The arguments that are mapped to other arguments, which form a permutation.
+    permutation: Vec<(usize, usize)>
+}
+
+impl Witness {
+
+    pub fn new(assignment: Vec<(usize, bool)>, permutation: Vec<(usize, usize)>) -> Witness {
+        Witness { assignment, permutation }
+    }
+
+    #[inline]
+    pub fn get_assignment(&self) -> &Vec<(usize, bool)> {
+        &self.assignment
+    }
+
+    #[inline]
+    pub fn get_permutation(&self) -> &Vec<(usize, usize)> {
+        &self.permutation
+    }
+
+    ///Returns the prefix of the permutation in the proof file format, i.e. 's{<argument>:<image>,...}', or None if there is no permutation.
+    pub fn to_permutation_prefix(&self) -> Option<String> {
+        if self.permutation.is_empty() {
+            return None;
+        }
+        let mappings : Vec<String> = self.permutation.iter().map(|(argument, image)| format!("{}:{}", argument + 1, image + 1)).collect();
+        Some(format!("s{{{}}}", mappings.join(",")))
+    }
+}
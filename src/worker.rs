@@ -1,15 +1,16 @@
-use std::sync::{Condvar, Mutex};
+use std::sync::{Arc, Condvar, Mutex};
 use std::{panic, thread};
+use std::thread::JoinHandle;
 use crate::instance_view::InstanceView;
-use crate::{INSTANCE, SUPERVISOR};
+use crate::supervisor::Supervisor;
 use crate::semantics::VerifierType;
 use crate::verifiers::admissibility_verifier::admissibility_verify;
 use crate::verifiers::rup_verifier::rup_verify;
 use crate::verifiers::stability_verifier::stability_verify;
 
 pub enum Work {
-    // Tells the worker to work on the clause with the given index.
-    Work(Option<usize>),
+    // Tells the worker to verify the clause with the given index.
+    Verify(Option<usize>),
     // Tells the worker to stall.
     Stall,
     // Tells the worker to terminate.
@@ -18,17 +19,19 @@ pub enum Work {
 
 pub struct Worker {
     id: usize,
-    park: (Mutex<(usize, usize)>, Condvar)
+    park: (Mutex<(usize, usize)>, Condvar),
+    handle: Mutex<Option<JoinHandle<()>>>
 }
 
 impl Worker {
 
-    pub fn new(id: usize) -> Worker {
+    pub fn new(id: usize, supervisor: Arc<Supervisor>) -> Worker {
         let mut worker = Worker {
             id,
-            park: (Mutex::new((0, 0)), Condvar::new())
+            park: (Mutex::new((0, 0)), Condvar::new()),
+            handle: Mutex::new(None)
         };
-        worker.do_work();
+        worker.do_work(supervisor);
         worker
     }
 
@@ -57,31 +60,40 @@ impl Worker {
         }
     }
 
-    fn do_work(&mut self) {
+    /// Waits until the thread of the worker has terminated, unless it has been joined already.
+    pub fn join(&self) {
+        let handle = self.handle.lock().ok().and_then(|mut handle| handle.take());
+        if let Some(handle) = handle {
+            _ = handle.join();
+        }
+    }
+
+    fn do_work(&mut self, supervisor: Arc<Supervisor>) {
         let id = self.id;
-        thread::spawn(move ||{
+        let handle = thread::spawn(move ||{
             let result = panic::catch_unwind(|| {
-                let mut instance = InstanceView::new(INSTANCE.get().unwrap());
+                let mut instance = InstanceView::new(supervisor.get_instance().unwrap());
 
                 //Main work loop
                 loop {
-                    match SUPERVISOR.get().unwrap().get_work(id) {
-                        Work::Work(id) => {
+                    match supervisor.get_work(id) {
+                        Work::Verify(id) => {
                             let verifier = if let Some(id) = id { instance.get_verifier(id) } else { &VerifierType::RUP };
                             let result = match verifier {
                                 VerifierType::Admissibility(index) => {
                                     let index = *index;
-                                    admissibility_verify(id.unwrap(), &mut instance, index) },
+                                    admissibility_verify(id.unwrap(), &instance, index) },
                                 VerifierType::RUP => {
-                                        instance.reset();
-                                        rup_verify(id, &mut instance)
+                                        instance.reset(id.unwrap_or(instance.get_number_of_clauses()));
+                                        rup_verify(id, &mut instance, &supervisor)
                                     },
-                                VerifierType::Stability => { stability_verify(id.unwrap(), &mut instance) }
+                                VerifierType::Stability => { stability_verify(id.unwrap(), &instance) },
+                                VerifierType::Assumption => true
                             };
-                            SUPERVISOR.get().unwrap().worker_finished(id, result);
+                            supervisor.worker_finished(id, result);
                         },
                         Work::Stall => {
-                            let worker = &SUPERVISOR.get().unwrap().get_workers()[id];
+                            let worker = &supervisor.get_workers()[id];
                             worker.stall();
                         },
                         Work::Finished => break //Exit main work loop
@@ -90,8 +102,9 @@ impl Worker {
             });
 
             if result.is_err() {
-                SUPERVISOR.get().unwrap().worker_error_occurred();
+                supervisor.worker_error_occurred();
             }
         });
+        self.handle = Mutex::new(Some(handle));
     }
 }
\ No newline at end of file
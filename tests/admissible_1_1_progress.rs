@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+use verifier::semantics::Semantics;
+use verifier::{verify};
+
+#[test]
+fn admissible_test_1_1_progress() {
+
+    let result = verify(1, PathBuf::from("./tests/data/admissible_1.ccl"), None, Some(PathBuf::from("./tests/data/admissible_1.required")),
+                        PathBuf::from("./tests/data/admissible_1_1.proof"), Semantics::Admissible, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    let progress = result.2.get_progress().unwrap();
+    assert_eq!(progress.queued, 0);
+    assert_eq!(progress.verified, 1);
+    assert_eq!(progress.used, 1);
+}
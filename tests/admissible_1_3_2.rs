@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 use verifier::semantics::Semantics;
-use verifier::{SUPERVISOR, verify};
+use verifier::{verify};
 
 #[test]
 fn admissible_test_1_3_2() {
@@ -8,7 +8,7 @@ fn admissible_test_1_3_2() {
     let result = verify(1, PathBuf::from("./tests/data/admissible_1.ccl"), None, Some(PathBuf::from("./tests/data/admissible_1.required")),
                         PathBuf::from("./tests/data/admissible_1_3.proof"), Semantics::Admissible, true);
     assert_eq!(result.1, verifier::EXIT_CODE_OK);
-    let result = SUPERVISOR.get().unwrap().get_result();
+    let result = result.2.get_result();
     assert_eq!(result.unwrap().1.unwrap(), 8);
 }
 
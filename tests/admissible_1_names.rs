@@ -0,0 +1,43 @@
+mod common;
+
+use std::path::PathBuf;
+use common::{content, create_job, parse_job};
+use verifier::instance_base::InstanceBase;
+use verifier::semantics::Semantics;
+use verifier::{verify, VerificationJob};
+
+/// Parses the proof for the admissible framework with the provided description.
+fn parse_proof(description: &str, proof: &str) -> Result<InstanceBase, String> {
+    parse_job(&VerificationJob { description: Some(content(description)), ..create_job("admissible_1.ccl", content(proof), Semantics::Admissible) })
+}
+
+#[test]
+fn admissible_test_1_names() {
+    let result = verify(1, PathBuf::from("./tests/data/admissible_1.ccl"), Some(PathBuf::from("./tests/data/admissible_1.description")), Some(PathBuf::from("./tests/data/admissible_1.required")),
+                        PathBuf::from("./tests/data/admissible_1_names.proof"), Semantics::Admissible, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+}
+
+#[test]
+fn admissible_test_1_names_resolved() {
+    //The names are replaced by the argument numbers and the attack named by its attackers by its index
+    let description = std::fs::read_to_string("./tests/data/admissible_1.description").unwrap();
+    let instance = parse_proof(&description, &std::fs::read_to_string("./tests/data/admissible_1_names.proof").unwrap()).unwrap();
+    let mut written = Vec::new();
+    instance.write_proof(&mut written, &|_| true).unwrap();
+    assert_eq!(String::from_utf8(written).unwrap(), "i7 -4 5 0\n5 0\n0\n");
+
+    //Quoted names are never read as numbers and attackers are a set
+    let instance = parse_proof("2 x\n3 y\n4 5\n5 4\n", "i{y,x,y} -\"5\" \"4\" 0\n0\n").unwrap();
+    assert_eq!(instance.proof_clauses[0].to_proof_line(), "i4 -4 5 0");
+}
+
+#[test]
+fn admissible_test_1_names_malformed() {
+    let description = "1 a1\n2 b\n3 c\n4 arg four\n5 e\n6 f\n6 e\n";
+    assert_eq!(parse_proof(description, "-g 0\n0\n").err().unwrap(), "The proof line '-g 0' contains an invalid argument '-g'");
+    assert_eq!(parse_proof(description, "-b e 0\n0\n").err().unwrap(), "The proof line '-b e 0' references argument name 'e' that is not unique");
+    assert_eq!(parse_proof(description, "i{b} -\"arg four\" 0\n0\n").err().unwrap(), "The proof line 'i{b} -\"arg four\" 0' names an attack by the attackers 'b' that does not attack the argument '4'");
+    assert_eq!(parse_proof(description, "i{-f} -\"arg four\" 0\n0\n").err().unwrap(), "The proof line 'i{-f} -\"arg four\" 0' names an attack by the negated attacker '-f'");
+}
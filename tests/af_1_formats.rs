@@ -0,0 +1,53 @@
+mod common;
+
+use common::{data, parse_framework};
+use verifier::framework_base::FrameworkBase;
+use verifier::parsers::file_reader::Source;
+use verifier::parsers::format::FrameworkFormat;
+
+/// Parses the framework and writes it in the provided format.
+fn convert(framework: Source, format: FrameworkFormat) -> String {
+    let framework = parse_framework(framework);
+    let mut output = Vec::new();
+    framework.write_as(&mut output, format, &|_| true).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn af_test_1_formats() {
+    let expected = "4 4 0\n2 1 0\n1 2 0\n3 2 0\n4 3 0\n";
+    for file in ["af_1.ccl", "af_1.af", "af_1.tgf"] {
+        assert_eq!(convert(data(file), FrameworkFormat::Ccl), expected, "{}", file);
+    }
+
+    //Inline content has no extension, thus its format is detected by the content
+    for format in [FrameworkFormat::Ccl, FrameworkFormat::Iccma, FrameworkFormat::Apx, FrameworkFormat::Tgf] {
+        let converted = convert(data("af_1.ccl"), format);
+        assert_eq!(convert(converted.into(), FrameworkFormat::Ccl), expected, "{:?}", format);
+    }
+
+    let tgf = convert(data("af_1.tgf"), FrameworkFormat::Tgf);
+    assert_eq!(tgf, "1\n2\n3\n4\n#\n1 2\n2 1\n2 3\n3 4\n");
+}
+
+#[test]
+fn af_test_1_formats_collective() {
+    let apx = convert(data("stable_1.ccl"), FrameworkFormat::Apx);
+    assert!(apx.contains("att(r5,a4).\nmem(r5,a2).\nmem(r5,a3).\n"));
+    assert_eq!(convert(apx.into(), FrameworkFormat::Ccl), std::fs::read_to_string("./tests/data/stable_1.ccl").unwrap() + "\n");
+
+    let framework = parse_framework(data("stable_1.ccl"));
+    assert!(framework.write_as(&mut Vec::new(), FrameworkFormat::Iccma, &|_| true).is_err());
+}
+
+#[test]
+fn af_test_1_formats_explicit() {
+    //The first node has a label that makes the file look like an instance file, thus only the provided format parses it
+    let tgf = Source::from("1 a 0\n2\n#\n1 2\n".to_string());
+    assert!(FrameworkBase::new(&tgf, None, &None, false).is_err());
+
+    let framework = FrameworkBase::new(&tgf, Some(FrameworkFormat::Tgf), &None, false).unwrap();
+    let mut output = Vec::new();
+    framework.write(&mut output, &|_| true).unwrap();
+    assert_eq!(String::from_utf8(output).unwrap(), "2 1 0\n2 1 0\n");
+}
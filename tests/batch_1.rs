@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+use verifier::batch::{parse_manifest, verify_batch, write_summary, Verdict};
+
+#[test]
+fn batch_test_1() {
+
+    let jobs = parse_manifest(&PathBuf::from("./tests/data/batch_1.manifest")).unwrap();
+    assert_eq!(jobs.len(), 5);
+
+    let results = verify_batch(&jobs, 2, 1, false, None, None).unwrap();
+    let verdicts : Vec<Verdict> = results.iter().map(|result| result.verdict).collect();
+    assert_eq!(verdicts, vec![Verdict::Verified, Verdict::Failed, Verdict::Failed, Verdict::Failed, Verdict::Invalid]);
+
+    let mut summary = Vec::new();
+    let mismatches = write_summary(&jobs, &results, &mut summary).unwrap();
+    assert_eq!(mismatches, 1);
+    assert_eq!(String::from_utf8(summary).unwrap().matches("MISMATCH").count(), 1);
+}
@@ -0,0 +1,43 @@
+//! Fixtures shared by the integration tests. Every test file only uses some of them.
+#![allow(dead_code)]
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use verifier::framework_base::FrameworkBase;
+use verifier::instance_base::InstanceBase;
+use verifier::parsers::file_reader::Source;
+use verifier::semantics::Semantics;
+use verifier::supervisor::Supervisor;
+use verifier::{verify_with_framework, VerificationJob};
+
+/// Returns the file with the provided name in the test data directory.
+pub fn data(file: &str) -> Source {
+    PathBuf::from("./tests/data").join(file).into()
+}
+
+/// Returns the provided content as inline file.
+pub fn content(content: &str) -> Source {
+    Source::from(content.to_string())
+}
+
+/// Creates the job for the proof of the framework in the test data directory. All other inputs of the job have their defaults.
+pub fn create_job(framework: &str, proof: Source, semantics: Semantics) -> VerificationJob {
+    VerificationJob::new(data(framework), proof, semantics)
+}
+
+/// Verifies the job with a single thread, asserts that the verification terminated regularly and returns its supervisor.
+pub fn run_job(job: VerificationJob, complete: bool) -> Arc<Supervisor> {
+    let result = verify_with_framework(1, job, None, complete, None, None);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK, "{}", result.0);
+    result.2
+}
+
+/// Parses the framework without description.
+pub fn parse_framework(framework: Source) -> Arc<FrameworkBase> {
+    Arc::new(FrameworkBase::new(&framework, None, &None, false).unwrap())
+}
+
+/// Parses the instance of the job without verifying it. Returns the message of the error if parsing fails.
+pub fn parse_job(job: &VerificationJob) -> Result<InstanceBase, String> {
+    InstanceBase::from_job(job, None).map_err(|error| error.message)
+}
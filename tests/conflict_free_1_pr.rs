@@ -0,0 +1,39 @@
+mod common;
+
+use common::{content, create_job, data, parse_job, run_job};
+use verifier::instance_base::InstanceBase;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Verifies the proof for the conflict-free framework.
+fn verify_proof(proof: &str, complete: bool) -> Option<(bool, Option<usize>)> {
+    let job = VerificationJob { required_arguments: Some(data("conflict_free_1.required")), ..create_job("conflict_free_1.ccl", content(proof), Semantics::ConflictFree) };
+    run_job(job, complete).get_result()
+}
+
+/// Parses the proof for the conflict-free framework.
+fn parse_proof(proof: &str, semantics: Semantics) -> Result<InstanceBase, String> {
+    parse_job(&create_job("conflict_free_1.ccl", content(proof), semantics))
+}
+
+#[test]
+fn conflict_free_test_1_pr() {
+    let proof = std::fs::read_to_string("./tests/data/conflict_free_1_pr.proof").unwrap();
+    assert_eq!(verify_proof(&proof, false), Some((true, None)));
+    assert_eq!(verify_proof(&proof, true), Some((true, None)));
+
+    //Without the witness, the clause '3 0' is no RAT, as the resolvent with '-3 -5 0' is not RUP
+    assert_eq!(verify_proof(&proof.replace("3 3 -5 0", "3 0"), true), Some((false, Some(5))));
+}
+
+#[test]
+fn conflict_free_test_1_pr_write() {
+    let instance = parse_proof("3 2 0\n3 3 -5 3 0\n0\n", Semantics::ConflictFree).unwrap();
+    assert_eq!(instance.proof_clauses[1].to_proof_line(), "3 3 -5 0");
+}
+
+#[test]
+fn conflict_free_test_1_pr_malformed() {
+    assert_eq!(parse_proof("3 2 3 -3 0\n0\n", Semantics::ConflictFree).err().unwrap(), "The witness of the proof line '3 2 3 -3 0' is inconsistent, as it contains the argument '3' with both signs");
+    assert_eq!(parse_proof("3 3 -5 0\n0\n", Semantics::Admissible).err().unwrap(), "The proof line '3 3 -5 0' contains a witness that assigns arguments, which is only supported for the semantics ConflictFree");
+}
@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+use verifier::semantics::Semantics;
+use verifier::verify;
+
+/// Verifies the proof for the conflict-free framework.
+fn verify_proof(proof: &str, semantics: Semantics, complete: bool) -> Option<(bool, Option<usize>)> {
+    let result = verify(1, PathBuf::from("./tests/data/conflict_free_1.ccl"), None, Some(PathBuf::from("./tests/data/conflict_free_1.required")),
+                        PathBuf::from(proof), semantics, complete);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    result.2.get_result()
+}
+
+#[test]
+fn conflict_free_test_1_rat() {
+    //The clause '3 0' is not RUP, but a RAT on 3 with the only clause that contains -3
+    assert_eq!(verify_proof("./tests/data/conflict_free_1_rat.proof", Semantics::ConflictFree, false), Some((true, None)));
+    assert_eq!(verify_proof("./tests/data/conflict_free_1_rat.proof", Semantics::ConflictFree, true), Some((true, None)));
+}
+
+#[test]
+fn conflict_free_test_1_rat_failed() {
+    //The resolvent with '-3 -5 0' is not RUP
+    assert_eq!(verify_proof("./tests/data/conflict_free_1_rat_failed.proof", Semantics::ConflictFree, true), Some((false, Some(4))));
+}
+
+#[test]
+fn conflict_free_test_1_rat_deletion() {
+    //Deleted clauses are no candidates for resolvents
+    assert_eq!(verify_proof("./tests/data/conflict_free_1_rat_deletion.proof", Semantics::ConflictFree, false), Some((true, None)));
+}
+
+#[test]
+fn conflict_free_test_1_rat_semantics() {
+    //The attacks are not the complete encoding of admissible sets
+    assert_eq!(verify_proof("./tests/data/conflict_free_1_rat.proof", Semantics::Admissible, false), Some((false, Some(3))));
+}
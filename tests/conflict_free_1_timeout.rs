@@ -0,0 +1,37 @@
+mod common;
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use common::{content, create_job};
+use verifier::semantics::Semantics;
+use verifier::supervisor::{Supervisor, SupervisorState};
+use verifier::verify_job;
+
+/// Creates a proof whose clause '<p> 6 0' before the empty clause takes quadratic time to verify: it is a RAT on p with k resolvents, each of which propagates along the chain of the extension variables e_1 to e_n.
+/// The extension variables are numbered e_1 to e_n, y_1 to y_k and p after the 5 arguments.
+fn create_proof(n: usize, k: usize) -> String {
+    let (e, y, p) = (|i: usize| 5 + i, |j: usize| 5 + n + j, 6 + n + k);
+    let mut proof = format!("-{} 1 0\n", e(1));
+    for i in 1..n {
+        proof += &format!("-{} {} 0\n", e(i), e(i + 1));
+    }
+    for j in 1..=k {
+        proof += &format!("-{} -{} 0\n", e(n), y(j));
+    }
+    for j in 1..=k {
+        proof += &format!("-{} {} 3 0\n", p, y(j));
+    }
+    proof + &format!("{} {} 0\n0\n", p, e(1))
+}
+
+#[test]
+fn conflict_free_test_1_timeout() {
+    let job = create_job("conflict_free_1.ccl", content(&create_proof(5000, 5000)), Semantics::ConflictFree);
+
+    //The clause is verified first, as the proof is verified completely, and its check is abandoned once the timeout has been reached. verify_job waits for the worker.
+    let supervisor = Arc::new(Supervisor::new(1));
+    let start_time = Instant::now();
+    verify_job(&supervisor, job, None, true, Some(Duration::from_millis(500)));
+    assert_eq!(supervisor.get_state(), SupervisorState::TimedOut);
+    assert!(start_time.elapsed() < Duration::from_secs(10));
+}
@@ -0,0 +1,25 @@
+mod common;
+
+use common::{content, create_job, run_job};
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Verifies the proof for the conflict-free framework with the provided required arguments and returns the required arguments that were used.
+fn verify_proof(required_arguments: &str, proof: &str) -> Vec<(usize, bool)> {
+    let job = VerificationJob { required_arguments: Some(content(required_arguments)), ..create_job("conflict_free_1.ccl", content(proof), Semantics::ConflictFree) };
+    let supervisor = run_job(job, true);
+    assert_eq!(supervisor.get_result(), Some((true, None)));
+    supervisor.get_instance().unwrap().get_used_required_arguments()
+}
+
+#[test]
+fn conflict_free_test_1_used_required() {
+    //The arguments '1' and '2' clash, as '1' attacks '2', while '3' and '-4' are not needed
+    assert_eq!(verify_proof("3\n1\n-4\n2\n", "0\n"), vec![(0, true), (1, true)]);
+
+    //The required argument '5' is the reason why the lemma '4 0' and then the empty clause propagate to a conflict
+    assert_eq!(verify_proof("5\n3\n", "4 0\n0\n"), vec![(4, true)]);
+
+    //The required argument '5' propagates while the lemma '2 0' is verified, but only '1' leads to the conflict
+    assert_eq!(verify_proof("5\n1\n", "2 0\n0\n"), vec![(0, true)]);
+}
@@ -0,0 +1,55 @@
+mod common;
+
+use std::sync::Arc;
+use common::{content, create_job, data, run_job};
+use verifier::enumeration::{encode_blocking, parse_extensions};
+use verifier::extension_constraint::ExtensionConstraint;
+use verifier::framework_base::FrameworkBase;
+use verifier::optimality::check_extension;
+use verifier::parsers::file_reader::Source;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Parses the conflict-free framework, whose arguments form a cycle.
+fn parse_framework() -> Arc<FrameworkBase> {
+    common::parse_framework(data("conflict_free_2.ccl"))
+}
+
+/// Verifies the proof that no conflict-free extension exists besides the provided ones.
+fn verify_proof(proof: &str, extensions: Vec<Vec<usize>>) -> Option<(bool, Option<usize>)> {
+    let job = VerificationJob { extension_constraint: Some(ExtensionConstraint::Blocked(extensions)), ..create_job("conflict_free_2.ccl", content(proof), Semantics::ConflictFree) };
+    run_job(job, true).get_result()
+}
+
+#[test]
+fn conflict_free_test_2_enumeration() {
+    let framework = parse_framework();
+    let extensions = parse_extensions(&data("conflict_free_2.extensions"), &framework, false).unwrap();
+    assert_eq!(extensions, vec![vec![], vec![0], vec![1], vec![2]]);
+    for extension in &extensions {
+        assert_eq!(check_extension(&framework, extension, &Semantics::ConflictFree), Ok(()));
+    }
+
+    let proof = std::fs::read_to_string("./tests/data/conflict_free_2_enumeration.proof").unwrap();
+    assert_eq!(verify_proof(&proof, extensions.clone()), Some((true, None)));
+
+    //The blocking clauses alone do not propagate to a conflict
+    assert_eq!(verify_proof("0\n", extensions), Some((false, None)));
+}
+
+#[test]
+fn conflict_free_test_2_enumeration_blocking() {
+    assert_eq!(encode_blocking(3, &[2, 0]), vec![(0, false), (1, true), (2, false)]);
+    assert_eq!(ExtensionConstraint::Blocked(vec![vec![], vec![1]]).encode(2), vec![vec![(0, true), (1, true)], vec![(0, true), (1, false)]]);
+}
+
+#[test]
+fn conflict_free_test_2_enumeration_malformed() {
+    let framework = parse_framework();
+    let parse = |extensions: &str| parse_extensions(&Source::from(extensions.to_string()), &framework, false).err().unwrap().message;
+    assert_eq!(parse("1 2\n"), "The extension line '1 2' is malformed");
+    assert_eq!(parse("-1 0\n"), "The extension line '-1 0' contains the negated argument '-1'");
+    assert_eq!(parse("1 1 0\n"), "The extension line '1 1 0' contains the argument '1' more than once");
+    assert_eq!(parse("1 3 0\n3 1 0\n"), "The extension line '3 1 0' lists an extension that has already been listed");
+    assert_eq!(parse("4 0\n"), "The clause '4 0' refers to an invalid argument '4'");
+}
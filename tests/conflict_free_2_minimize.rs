@@ -0,0 +1,33 @@
+mod common;
+
+use common::{content, create_job, parse_job};
+use verifier::core_minimization::minimize_core;
+use verifier::instance_base::InstanceBase;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Creates the instance of the conflict-free framework with the provided required arguments and proof.
+fn create_instance(required_arguments: &str, proof: &str) -> InstanceBase {
+    parse_job(&VerificationJob { required_arguments: Some(content(required_arguments)), ..create_job("conflict_free_2.ccl", content(proof), Semantics::ConflictFree) }).unwrap()
+}
+
+#[test]
+fn conflict_free_test_2_minimize() {
+    //Every attack of the cycle clashes with the required arguments, but one of them suffices
+    let core = minimize_core(1, create_instance("1\n2\n3\n", "0\n"), false, None).unwrap();
+    assert!(core.minimal);
+    assert_eq!(core.attacks.len(), 1);
+
+    //Only the attack of '1' on '2' clashes with the required arguments
+    let core = minimize_core(2, create_instance("1\n2\n", "0\n"), false, None).unwrap();
+    assert!(core.minimal);
+    assert_eq!(core.attacks, vec![0]);
+
+    //The required arguments '2' and '3' clash due to the attack of '2' on '3', even if the lemma is verified completely
+    let core = minimize_core(1, create_instance("2\n3\n", "-1 -2 0\n0\n"), true, None).unwrap();
+    assert!(core.minimal);
+    assert_eq!(core.attacks, vec![1]);
+
+    //The required argument '1' alone is not in conflict with any attack
+    assert_eq!(minimize_core(1, create_instance("1\n", "0\n"), false, None).err().map(|error| error.1), Some(verifier::EXIT_CODE_FAILURE));
+}
@@ -0,0 +1,75 @@
+mod common;
+
+use std::sync::Arc;
+use common::{content, create_job, data, run_job};
+use verifier::framework_base::FrameworkBase;
+use verifier::optimality::{check_extension, encode_at_least, parse_extension};
+use verifier::parsers::file_reader::Source;
+use verifier::extension_constraint::ExtensionConstraint;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Parses the conflict-free framework, whose arguments form a cycle.
+fn parse_framework() -> Arc<FrameworkBase> {
+    common::parse_framework(data("conflict_free_2.ccl"))
+}
+
+/// Verifies the proof that no conflict-free extension contains the provided number of arguments.
+fn verify_proof(proof: &str, minimum_extension_size: usize) -> Option<(bool, Option<usize>)> {
+    let job = VerificationJob { extension_constraint: Some(ExtensionConstraint::MinimumSize(minimum_extension_size)), ..create_job("conflict_free_2.ccl", content(proof), Semantics::ConflictFree) };
+    run_job(job, true).get_result()
+}
+
+#[test]
+fn conflict_free_test_2_optimum() {
+    let framework = parse_framework();
+    let extension = parse_extension(&data("conflict_free_2.extension"), &framework, false).unwrap();
+    assert_eq!(extension, vec![0]);
+    assert_eq!(check_extension(&framework, &extension, &Semantics::ConflictFree), Ok(()));
+
+    let proof = std::fs::read_to_string("./tests/data/conflict_free_2_optimum.proof").unwrap();
+    assert_eq!(verify_proof(&proof, extension.len() + 1), Some((true, None)));
+
+    //The cardinality constraint alone does not propagate to a conflict
+    assert_eq!(verify_proof("0\n", extension.len() + 1), Some((false, None)));
+}
+
+#[test]
+fn conflict_free_test_2_optimum_extension() {
+    let framework = parse_framework();
+    assert_eq!(check_extension(&framework, &[0, 1], &Semantics::ConflictFree).err().unwrap(), "The extension is not conflict-free, as it contains the argument '2' as well as the attackers of the attack '2 1 0'");
+    assert_eq!(check_extension(&framework, &[0], &Semantics::Admissible).err().unwrap(), "The extension is not admissible, as it does not defend the argument '1' against the attack '1 3 0'");
+    assert_eq!(check_extension(&framework, &[0], &Semantics::Stable).err().unwrap(), "The extension is not stable, as it neither contains nor attacks the argument '3'");
+    assert_eq!(check_extension(&framework, &[], &Semantics::Admissible), Ok(()));
+
+    assert_eq!(parse_extension(&Source::from("-1\n".to_string()), &framework, false).err().unwrap().message, "The extension file contains the negated argument '-1'");
+    assert_eq!(parse_extension(&Source::from("1\n1\n".to_string()), &framework, false).err().unwrap().message, "The extension file contains the argument '1' more than once");
+}
+
+#[test]
+fn conflict_free_test_2_optimum_encoding() {
+    assert_eq!(encode_at_least(3, 2), vec![
+        vec![(3, false), (0, true)],
+        vec![(4, false), (3, true), (1, true)],
+        vec![(5, false), (1, true)],
+        vec![(5, false), (3, true)],
+        vec![(6, false), (5, true), (2, true)],
+        vec![(6, false), (5, true), (4, true)],
+        vec![(6, true)]
+    ]);
+
+    //The encoding is satisfiable for an assignment of the arguments iff at least minimum of them are true
+    for number_of_arguments in 1..=5 {
+        for minimum in 1..=number_of_arguments {
+            let clauses = encode_at_least(number_of_arguments, minimum);
+            let number_of_variables = clauses.iter().flatten().map(|(variable, _)| variable + 1).max().unwrap();
+            for arguments in 0_usize..1 << number_of_arguments {
+                let satisfiable = (0_usize..1 << (number_of_variables - number_of_arguments)).any(|counters| {
+                    let value = |variable: usize| if variable < number_of_arguments { arguments >> variable & 1 == 1 } else { counters >> (variable - number_of_arguments) & 1 == 1 };
+                    clauses.iter().all(|clause| clause.iter().any(|(variable, sign)| value(*variable) == *sign))
+                });
+                assert_eq!(satisfiable, arguments.count_ones() as usize >= minimum);
+            }
+        }
+    }
+}
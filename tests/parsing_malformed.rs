@@ -0,0 +1,130 @@
+use std::ops::Range;
+use std::panic;
+use std::sync::Arc;
+use std::time::Duration;
+use verifier::instance_base::InstanceBase;
+use verifier::parsers::file_reader::Source;
+use verifier::parsers::parse_error::ParseError;
+use verifier::semantics::Semantics;
+use verifier::supervisor::{Supervisor, SupervisorState};
+use verifier::{verify_job, VerificationJob};
+use proptest::{collection, option, prelude::*, sample};
+
+/// Creates a job with inline content for the provided framework, required arguments and proof.
+fn create_job(framework: &str, required: Option<&str>, proof: &str, semantics: Semantics) -> VerificationJob {
+    VerificationJob {
+        required_arguments: required.map(|required| required.to_string().into()),
+        ..VerificationJob::new(framework.to_string().into(), proof.to_string().into(), semantics)
+    }
+}
+
+/// Parses and verifies the job and asserts that neither panics. Returns the error message if parsing failed.
+fn check(job: VerificationJob) -> Option<ParseError> {
+    let parsed = panic::catch_unwind(panic::AssertUnwindSafe(|| InstanceBase::from_job(&job, None)));
+    assert!(parsed.is_ok(), "Parsing panicked for the proof {:?}", String::from_utf8_lossy(job_proof(&job)));
+
+    let supervisor = Arc::new(Supervisor::new(1));
+    verify_job(&supervisor, job.clone(), None, true, Some(Duration::from_secs(10)));
+    assert_ne!(supervisor.get_state(), SupervisorState::UnexpectedError, "Verification panicked for the proof {:?}", String::from_utf8_lossy(job_proof(&job)));
+
+    parsed.unwrap().err()
+}
+
+/// Returns the inline content of the proof of the job.
+fn job_proof(job: &VerificationJob) -> &[u8] {
+    match &job.proof {
+        Source::Content(content) => content,
+        Source::Path(_) | Source::Stdin => &[]
+    }
+}
+
+const FRAMEWORK: &str = "6 8 0\n2 1 0\n3 1 0\n1 2 0\n1 3 0\n4 2 3 0\n5 4 0\n6 5 0\n4 6 0\n";
+
+#[test]
+fn parsing_malformed_regressions() {
+    let cases = [
+        (FRAMEWORK, "1 0 2 0\n0\n", Semantics::Stable),
+        (FRAMEWORK, "1 -0 2 0\n0\n", Semantics::Stable),
+        (FRAMEWORK, "-9223372036854775808 0\n0\n", Semantics::Stable),
+        (FRAMEWORK, "i 0\n", Semantics::Stable),
+        (FRAMEWORK, "i 0\n", Semantics::Admissible),
+        (FRAMEWORK, "i 0\n0\n", Semantics::Admissible),
+        (FRAMEWORK, "d\n0\n", Semantics::Stable),
+        (FRAMEWORK, "dä\n0\n", Semantics::Stable),
+        ("18446744073709551615 1 0\n1 1 0\n", "0\n", Semantics::Stable),
+        ("1 18446744073709551615 0\n1 1 0\n", "0\n", Semantics::Stable),
+        ("6 9 0\n2 1 0\n", "0\n", Semantics::Stable),
+        ("arg(a).\natt(a,b).\n", "0\n", Semantics::Stable),
+        ("arg(a).\nmem(r,a).\n", "0\n", Semantics::Stable),
+        ("arg(a). att(a,a)\n", "0\n", Semantics::Stable),
+        ("p af 2\n1 3\n", "0\n", Semantics::Stable),
+        ("1\n#\n1 2\n", "0\n", Semantics::Stable)
+    ];
+
+    for (framework, proof, semantics) in cases {
+        assert!(check(create_job(framework, None, proof, semantics)).is_some());
+    }
+}
+
+#[test]
+fn parsing_malformed_huge_preamble() {
+    let cases = [
+        ("120000000 0 0", "\n"),
+        ("120000000 0 0\n", "0\n"),
+        ("p af 120000000\n1 2\n", "0\n")
+    ];
+
+    for (framework, proof) in cases {
+        let error = check(create_job(framework, None, proof, Semantics::Stable));
+        assert!(error.is_some_and(|error| error.message.contains("is too large")), "The framework {:?} was not rejected", framework);
+    }
+}
+
+/// Returns a line that starts with one of the prefixes, followed by up to four tokens and, usually, the terminating 0.
+fn line(prefixes: &'static [&'static str]) -> impl Strategy<Value = String> {
+    const TOKENS: [&str; 18] = ["0", "-0", "1", "-1", "2", "-2", "4", "-5", "6", "7", "-7", "00", "+3", "x", "", "18446744073709551616", "-9223372036854775808", "ä"];
+    let token = prop_oneof![4 => sample::select(&TOKENS[..]).prop_map(str::to_string), 1 => any::<i64>().prop_map(|number| number.to_string())];
+    (sample::select(prefixes), collection::vec(token, 0..5), any::<bool>()).prop_map(|(prefix, tokens, terminated)| {
+        let mut line = prefix.to_string();
+        for token in tokens {
+            line.push_str(&token);
+            line.push(' ');
+        }
+        if terminated {
+            line.push('0');
+        }
+        line
+    })
+}
+
+/// Returns the lines produced by the strategy, each followed by a line break.
+fn lines(line: impl Strategy<Value = String>, size: Range<usize>) -> impl Strategy<Value = String> {
+    collection::vec(line, size).prop_map(|lines| lines.into_iter().map(|line| line + "\n").collect())
+}
+
+fn semantics() -> impl Strategy<Value = Semantics> {
+    sample::select(vec![Semantics::ConflictFree, Semantics::Admissible, Semantics::Stable])
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(1000))]
+
+    #[test]
+    fn parsing_malformed_generated(
+        header in sample::select(&["6 8 0", "6 2 0", "0 0 0", "6 x 0", "6 8", ""][..]),
+        attacks in lines(line(&["", "", "#", "s "]), 0..10),
+        required in option::of(line(&["", "-", "s ", "s -"])),
+        clauses in lines(line(&["", "", "i ", "i3 ", "i0 ", "ix ", "d ", "d", "#"]), 0..6),
+        terminated in any::<bool>(),
+        semantics in semantics()
+    ) {
+        let framework = format!("{}\n{}", header, attacks);
+        let proof = if terminated { clauses + "0\n" } else { clauses };
+        check(create_job(&framework, required.as_deref(), &proof, semantics));
+    }
+
+    #[test]
+    fn parsing_malformed_bytes(framework in collection::vec(any::<u8>(), 0..64), proof in collection::vec(any::<u8>(), 0..64), semantics in semantics()) {
+        check(VerificationJob::new(Source::Content(Arc::from(framework)), Source::Content(Arc::from(proof)), semantics));
+    }
+}
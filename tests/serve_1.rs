@@ -0,0 +1,51 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::Shutdown;
+use std::os::unix::net::UnixStream;
+use std::thread;
+use std::time::Duration;
+use serde_json::Value;
+use verifier::deletion_policy::DeletionPolicy;
+use verifier::server::{Server, ServeOptions};
+
+#[test]
+fn serve_test_1(){
+    let socket_path = std::env::temp_dir().join(format!("verifier_serve_test_1_{}.sock", std::process::id()));
+    let options = ServeOptions { parallel_jobs: 1, number_of_threads: 1, complete: false, timeout: None, cache_size: 4, max_connections: 1, strict: false, deletion_policy: DeletionPolicy::HonourAll };
+    let server = Server::bind(&socket_path, options).unwrap();
+    thread::spawn(move || server.run());
+
+    let proof = fs::read_to_string("./tests/data/stable_1_3.proof").unwrap();
+    let requests = [
+        serde_json::json!({"id": "path", "instance": "./tests/data/stable_1.ccl", "required": "./tests/data/stable_1.required", "proof": "./tests/data/stable_1_1.proof", "semantics": "stable"}),
+        serde_json::json!({"id": "inline", "instance": "./tests/data/stable_1.ccl", "required_content": "1\n", "proof_content": proof, "semantics": "Stable"}),
+        serde_json::json!({"instance": "./tests/data/stable_1.ccl", "semantics": "Stable"})
+    ];
+
+    let mut stream = UnixStream::connect(&socket_path).unwrap();
+    stream.set_read_timeout(Some(Duration::from_secs(60))).unwrap();
+    for request in &requests {
+        writeln!(stream, "{}", request).unwrap();
+    }
+
+    //The first connection is still open, thus a second one is rejected
+    let rejected = UnixStream::connect(&socket_path).unwrap();
+    rejected.set_read_timeout(Some(Duration::from_secs(60))).unwrap();
+    let rejection : Value = serde_json::from_str(&BufReader::new(rejected).lines().next().unwrap().unwrap()).unwrap();
+    assert_eq!(rejection["verdict"], "error");
+
+    stream.shutdown(Shutdown::Write).unwrap();
+
+    let responses : HashMap<String, Value> = BufReader::new(stream).lines().take(requests.len())
+        .map(|line| serde_json::from_str::<Value>(&line.unwrap()).unwrap())
+        .map(|response| (response["id"].to_string(), response))
+        .collect();
+
+    assert_eq!(responses["\"path\""]["verdict"], "verified");
+    assert_eq!(responses["\"path\""]["cached"], false);
+    assert_eq!(responses["\"inline\""]["verdict"], "failed");
+    assert_eq!(responses["\"inline\""]["details"], "The proof clause with (0-based) index 1 failed to verify.");
+    assert_eq!(responses["\"inline\""]["cached"], true);
+    assert_eq!(responses["3"]["verdict"], "invalid");
+}
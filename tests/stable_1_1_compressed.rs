@@ -0,0 +1,29 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use verifier::semantics::Semantics;
+use verifier::verify;
+
+#[test]
+fn stable_test_1_1_compressed() {
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl.gz"), None, Some(PathBuf::from("./tests/data/stable_1.required.zst")),
+                        PathBuf::from("./tests/data/stable_1_1.proof.xz"), Semantics::Stable, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+}
+
+#[test]
+fn stable_test_1_1_compressed_stdin() {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_verifier"))
+        .args(["verify", "-i", "./tests/data/stable_1.ccl.gz", "-r", "./tests/data/stable_1.required", "-p", "-", "-s", "Stable"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child.stdin.take().unwrap().write_all(&fs::read("./tests/data/stable_1_1.proof.zst").unwrap()).unwrap();
+
+    let output = child.wait_with_output().unwrap();
+    assert_eq!(output.status.code(), Some(verifier::EXIT_CODE_OK as i32));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Proof verified successfully."));
+}
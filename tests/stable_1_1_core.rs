@@ -0,0 +1,27 @@
+use std::fs;
+use std::path::PathBuf;
+use verifier::semantics::Semantics;
+use verifier::{verify};
+
+#[test]
+fn stable_test_1_1_core() {
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                        PathBuf::from("./tests/data/stable_1_1.proof"), Semantics::Stable, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+
+    let instance = result.2.get_instance().unwrap();
+    let mut core = Vec::new();
+    instance.framework.write(&mut core, &|index| instance.clause_states[index].is_used()).unwrap();
+    let core = String::from_utf8(core).unwrap();
+    assert!(core.starts_with("6 "));
+
+    //The proof has to verify for the core as well
+    let core_path = std::env::temp_dir().join(format!("verifier_stable_test_1_1_core_{}.ccl", std::process::id()));
+    fs::write(&core_path, core).unwrap();
+    let result = verify(1, core_path.clone(), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                        PathBuf::from("./tests/data/stable_1_1.proof"), Semantics::Stable, false);
+    _ = fs::remove_file(core_path);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+}
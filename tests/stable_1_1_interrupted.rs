@@ -0,0 +1,18 @@
+mod common;
+
+use common::{create_job, data, run_job};
+use verifier::semantics::Semantics;
+use verifier::{stop_all, verify_with_framework, VerificationJob};
+
+#[test]
+fn stable_test_1_1_interrupted() {
+    let job = VerificationJob { required_arguments: Some(data("stable_1.required")), ..create_job("stable_1.ccl", data("stable_1_1.proof"), Semantics::Stable) };
+
+    stop_all();
+    let result = verify_with_framework(1, job.clone(), None, false, None, None);
+    assert_eq!(result.1, verifier::EXIT_CODE_SIGNALS, "{}", result.0);
+
+    //The interruption does not carry over to the next verification
+    let supervisor = run_job(job, false);
+    assert_eq!(supervisor.get_result(), Some((true, None)));
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::{content, create_job, data, parse_job};
+use verifier::instance_base::InstanceBase;
+use verifier::parsers::file_reader::set_memory_mapping;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Parses the proof for stable_1. Uncompressed files are memory-mapped, while compressed ones are read as a stream.
+fn parse(proof: &str) -> Result<InstanceBase, String> {
+    set_memory_mapping(true);
+    let job = VerificationJob { required_arguments: Some(data("stable_1.required")), ..create_job("stable_1.ccl", data(proof), Semantics::Stable) };
+    InstanceBase::from_job(&job, None).map_err(|error| format!("{:?}:{:?}: {}", error.line_number, error.column, error.message))
+}
+
+/// Returns the proof clauses of the instance as written to a proof file.
+fn write(instance: &InstanceBase) -> String {
+    let mut output = Vec::new();
+    instance.write_proof(&mut output, &|_| true).unwrap();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn stable_test_1_1_mapped() {
+    let mapped = parse("stable_1_1.proof").unwrap();
+    let streamed = parse("stable_1_1.proof.zst").unwrap();
+    assert_eq!(write(&mapped), write(&streamed));
+    assert_eq!(mapped.unit_clauses, streamed.unit_clauses);
+    for (mapped_clause, streamed_clause) in mapped.proof_clauses.iter().zip(&streamed.proof_clauses) {
+        assert_eq!(mapped_clause.get_members(), streamed_clause.get_members());
+    }
+
+    let inline = parse_job(&create_job("stable_1.ccl", content(&std::fs::read_to_string("./tests/data/stable_1_1.proof").unwrap()), Semantics::Stable)).unwrap();
+    assert_eq!(write(&mapped), write(&inline));
+}
+
+#[test]
+fn stable_test_1_1_mapped_error() {
+    let mapped = parse("stable_1_parse_error.proof").err().unwrap();
+    let streamed = parse("stable_1_parse_error.proof.gz").err().unwrap();
+    assert_eq!(mapped, streamed);
+    assert_eq!(mapped, "Some(4):Some(7): The proof line 'i 5 4 x 0' contains an invalid argument 'x'");
+}
@@ -0,0 +1,26 @@
+use std::fs;
+use std::path::PathBuf;
+use verifier::semantics::Semantics;
+use verifier::{verify};
+
+#[test]
+fn stable_test_1_1_trim() {
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                        PathBuf::from("./tests/data/stable_1_1.proof"), Semantics::Stable, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+
+    let instance = result.2.get_instance().unwrap();
+    let mut trimmed = Vec::new();
+    instance.write_proof(&mut trimmed, &|index| instance.clause_states[index].is_used()).unwrap();
+    assert_eq!(String::from_utf8(trimmed.clone()).unwrap(), "-5 2 0\ni 5 4 0\ni 6 5 0\n0\n");
+
+    //The trimmed proof has to verify as well
+    let trimmed_path = std::env::temp_dir().join(format!("verifier_stable_test_1_1_trim_{}.proof", std::process::id()));
+    fs::write(&trimmed_path, trimmed).unwrap();
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                        trimmed_path.clone(), Semantics::Stable, false);
+    _ = fs::remove_file(trimmed_path);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+}
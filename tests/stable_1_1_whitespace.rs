@@ -0,0 +1,31 @@
+mod common;
+
+use common::data;
+use verifier::semantics::Semantics;
+use verifier::{verify_with_framework, VerificationJob};
+
+/// Creates the job for the instance and proof with tabs, double and trailing spaces, blank lines and CRLF line endings.
+fn create_job(strict: bool) -> VerificationJob {
+    VerificationJob {
+        required_arguments: Some(data("stable_1.required")),
+        strict,
+        ..common::create_job("stable_1_whitespace.ccl", data("stable_1_1_whitespace.proof"), Semantics::Stable)
+    }
+}
+
+#[test]
+fn stable_test_1_1_whitespace() {
+    let result = verify_with_framework(1, create_job(false), None, false, None, None);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+}
+
+#[test]
+fn stable_test_1_1_whitespace_strict() {
+    let result = verify_with_framework(1, create_job(true), None, false, None, None);
+    assert_eq!(result.1, verifier::EXIT_CODE_INSTANCE);
+
+    let error = result.2.get_parsing_error();
+    assert_eq!(error.file.as_deref(), Some("./tests/data/stable_1_whitespace.ccl"));
+    assert_eq!(error.line_number, Some(1));
+}
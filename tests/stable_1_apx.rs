@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+use verifier::semantics::Semantics;
+use verifier::verify;
+
+#[test]
+fn stable_test_1_apx() {
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.apx"), None, Some(PathBuf::from("./tests/data/stable_1_apx.required")),
+                        PathBuf::from("./tests/data/stable_1_1.proof"), Semantics::Stable, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+
+    let instance = result.2.get_instance().unwrap();
+    assert_eq!(instance.framework.argument_names.get("a"), Some(&Some(0)));
+    assert_eq!(instance.required_arguments, vec![(0, true)]);
+}
@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use verifier::semantics::Semantics;
+use verifier::supervisor::Supervisor;
+use verifier::{verify};
+
+/// Verifies the proof for the stable framework.
+fn verify_proof(proof: &str) -> (String, u8, Arc<Supervisor>) {
+    verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+           PathBuf::from(proof), Semantics::Stable, false)
+}
+
+#[test]
+fn stable_test_1_deletion() {
+    //Deletes an attack and a proof clause with reordered members
+    let result = verify_proof("./tests/data/stable_1_deletion.proof");
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+}
+
+#[test]
+fn stable_test_1_deletion_attack() {
+    //The empty clause cannot be derived once the attack '4 6 0' is deleted
+    let result = verify_proof("./tests/data/stable_1_deletion_attack.proof");
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert_eq!(result.2.get_result(), Some((false, None)));
+}
+
+#[test]
+fn stable_test_1_deletion_ambiguous() {
+    let result = verify_proof("./tests/data/stable_1_deletion_ambiguous.proof");
+    assert_eq!(result.1, verifier::EXIT_CODE_INSTANCE);
+
+    let error = result.2.get_parsing_error();
+    assert_eq!(error.line_number, Some(3));
+    assert_eq!(error.message, "Clause deletion line 'd -1 -2 0' is ambiguous, as it references the attack '2 1 0' as well as the proof clause '-2 -1 0'");
+}
@@ -0,0 +1,42 @@
+mod common;
+
+use std::sync::Arc;
+use common::{create_job, data, run_job};
+use verifier::deletion_policy::DeletionPolicy;
+use verifier::semantics::Semantics;
+use verifier::supervisor::Supervisor;
+use verifier::VerificationJob;
+
+/// Verifies the proof that deletes the unit clause '-5 0' and the clause '-5 2 0', which are needed for the empty clause, with the provided deletion policy.
+fn verify_proof(deletion_policy: DeletionPolicy) -> Arc<Supervisor> {
+    let job = VerificationJob {
+        required_arguments: Some(data("stable_1.required")),
+        deletion_policy,
+        ..create_job("stable_1.ccl", data("stable_1_deletion_unit.proof"), Semantics::Stable)
+    };
+    run_job(job, false)
+}
+
+#[test]
+fn stable_test_1_deletion_policy_honour_all() {
+    let supervisor = verify_proof(DeletionPolicy::HonourAll);
+    assert_eq!(supervisor.get_result(), Some((false, None)));
+    assert!(supervisor.get_deleted_reasons().is_empty());
+}
+
+#[test]
+fn stable_test_1_deletion_policy_ignore_units() {
+    let supervisor = verify_proof(DeletionPolicy::IgnoreUnits);
+    assert_eq!(supervisor.get_result(), Some((true, None)));
+}
+
+#[test]
+fn stable_test_1_deletion_policy_warn_reasons() {
+    let supervisor = verify_proof(DeletionPolicy::WarnReasons);
+    assert_eq!(supervisor.get_result(), Some((false, None)));
+
+    //Both deleted clauses would have propagated during the verification of the empty clause
+    let instance = supervisor.get_instance().unwrap();
+    let empty_clause = instance.get_number_of_clauses();
+    assert_eq!(supervisor.get_deleted_reasons(), vec![(instance.proof_start + 1, empty_clause), (instance.proof_start + 5, empty_clause)]);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{data, parse_framework};
+use verifier::dimacs::DimacsEncoding;
+use verifier::framework_base::FrameworkBase;
+use verifier::optimality::check_extension;
+use verifier::semantics::Semantics;
+
+/// Checks that the sets of arguments that satisfy the encoding together with some assignment of the auxiliary variables are exactly the extensions.
+/// The auxiliary variable of an attack is assigned true if all of its attackers are contained, which satisfies the encoding if any assignment does.
+fn check_encoding(framework: &FrameworkBase, semantics: &Semantics) {
+    let encoding = DimacsEncoding::new(framework, semantics, &[]);
+    let number_of_arguments = framework.arguments.len();
+    for set in 0..1usize << number_of_arguments {
+        let contained = |argument: usize| set & 1 << argument != 0;
+        let value = |variable: usize| match variable <= number_of_arguments {
+            true => contained(variable - 1),
+            false => framework.attacks[variable - number_of_arguments - 1].get_members()[1..].iter().all(|(attacker, _)| contained(*attacker))
+        };
+        let satisfied = encoding.get_clauses().iter().all(|clause| clause.iter().any(|literal| value(literal.unsigned_abs() as usize) == literal.is_positive()));
+        let extension : Vec<usize> = (0..number_of_arguments).filter(|argument| contained(*argument)).collect();
+        assert_eq!(satisfied, check_extension(framework, &extension, semantics).is_ok(), "{:?}", extension);
+    }
+}
+
+#[test]
+fn stable_test_1_dimacs() {
+    let framework = parse_framework(data("stable_1.ccl"));
+    check_encoding(&framework, &Semantics::ConflictFree);
+    check_encoding(&framework, &Semantics::Admissible);
+    check_encoding(&framework, &Semantics::Stable);
+
+    //The required argument '1' is a unit clause
+    let encoding = DimacsEncoding::new(&framework, &Semantics::Stable, &[(0, true)]);
+    assert_eq!(encoding.get_clauses().last(), Some(&vec![1]));
+    assert_eq!(encoding.get_number_of_variables(), 14);
+
+    let mut cnf = Vec::new();
+    encoding.write(&mut cnf).unwrap();
+    let cnf = String::from_utf8(cnf).unwrap();
+    assert!(cnf.contains("p cnf 14 24\n"));
+    assert!(cnf.contains("\n4 11 14 0\n"));
+}
+
+#[test]
+fn conflict_free_test_1_dimacs() {
+    let framework = parse_framework(data("conflict_free_1.ccl"));
+    check_encoding(&framework, &Semantics::ConflictFree);
+    check_encoding(&framework, &Semantics::Admissible);
+    check_encoding(&framework, &Semantics::Stable);
+}
@@ -0,0 +1,92 @@
+mod common;
+
+use std::collections::HashMap;
+use common::{create_job, data, run_job};
+use verifier::dimacs::DimacsEncoding;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Verifies the proof and returns the DRAT proof of its used clauses together with the encoding.
+fn convert_proof(name: &str, required_arguments: &str, proof: &str, semantics: Semantics, complete: bool) -> (Vec<Vec<i64>>, String) {
+    let job = VerificationJob {
+        required_arguments: Some(data(&format!("{}.required", required_arguments))),
+        ..create_job(&format!("{}.ccl", name), data(&format!("{}.proof", proof)), semantics.clone())
+    };
+    let supervisor = run_job(job, complete);
+    assert_eq!(supervisor.get_result(), Some((true, None)));
+
+    let instance = supervisor.get_instance().unwrap();
+    let is_used = |index: usize| instance.clause_states[index].is_used();
+    assert_eq!(DimacsEncoding::find_clause_with_witness(instance, &is_used), None);
+    let encoding = DimacsEncoding::from_instance(instance, &semantics);
+    let mut drat = Vec::new();
+    encoding.write_drat(instance, &mut drat, &is_used).unwrap();
+    (encoding.get_clauses().clone(), String::from_utf8(drat).unwrap())
+}
+
+/// Checks that every lemma of the DRAT proof is implied by reverse unit propagation and that it ends with the empty clause.
+fn check_rup_proof(mut clauses: Vec<Vec<i64>>, drat: &str) {
+    let mut last_line = "";
+    for line in drat.lines() {
+        let (deletion, line) = match line.strip_prefix("d ") {
+            Some(line) => (true, line),
+            None => (false, line)
+        };
+        let mut clause : Vec<i64> = line.split(' ').map(|literal| literal.parse().unwrap()).collect();
+        assert_eq!(clause.pop(), Some(0));
+        if deletion {
+            let position = clauses.iter().position(|other| other.len() == clause.len() && other.iter().all(|literal| clause.contains(literal))).unwrap();
+            clauses.remove(position);
+            continue;
+        }
+
+        //Assign the negated lemma and propagate until a conflict is found
+        let mut assignment : HashMap<u64, bool> = clause.iter().map(|literal| (literal.unsigned_abs(), !literal.is_positive())).collect();
+        let conflict = loop {
+            let mut propagated = false;
+            let mut conflict = false;
+            for other in &clauses {
+                let open : Vec<&i64> = other.iter().filter(|literal| !assignment.contains_key(&literal.unsigned_abs())).collect();
+                if other.iter().any(|literal| assignment.get(&literal.unsigned_abs()) == Some(&literal.is_positive())) {
+                    continue;
+                }
+                match open.len() {
+                    0 => conflict = true,
+                    1 => {
+                        assignment.insert(open[0].unsigned_abs(), open[0].is_positive());
+                        propagated = true;
+                    },
+                    _ => {}
+                }
+            }
+            if conflict || !propagated {
+                break conflict;
+            }
+        };
+        assert!(conflict, "The lemma '{}' is not implied by reverse unit propagation", line);
+        clauses.push(clause);
+        last_line = line;
+    }
+    assert_eq!(last_line, "0");
+}
+
+#[test]
+fn stable_test_1_drat() {
+    let (clauses, drat) = convert_proof("stable_1", "stable_1", "stable_1_1", Semantics::Stable, true);
+    assert_eq!(drat, "4 6 2 0\n-5 2 0\n5 4 0\n6 5 0\n-4 -5 0\n0\n");
+    check_rup_proof(clauses, &drat);
+
+    let (clauses, drat) = convert_proof("stable_1", "stable_1", "stable_1_deletion", Semantics::Stable, true);
+    assert!(drat.starts_with("d -3 -1 0\n"));
+    check_rup_proof(clauses, &drat);
+
+    let (clauses, drat) = convert_proof("stable_1", "stable_1", "stable_1_1", Semantics::Stable, false);
+    check_rup_proof(clauses, &drat);
+}
+
+#[test]
+fn admissible_test_1_drat() {
+    let (clauses, drat) = convert_proof("admissible_1", "admissible_1", "admissible_1_1", Semantics::Admissible, true);
+    assert_eq!(drat, "-4 5 0\n5 0\n0\n");
+    check_rup_proof(clauses, &drat);
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use std::path::PathBuf;
+use common::{content, create_job, parse_job};
+use verifier::instance_base::InstanceBase;
+use verifier::semantics::Semantics;
+use verifier::statistics::ProofStatistics;
+use verifier::verify;
+
+/// Parses the proof for the stable framework.
+fn parse_proof(proof: &str) -> Result<InstanceBase, String> {
+    parse_job(&create_job("stable_1.ccl", content(proof), Semantics::Stable))
+}
+
+#[test]
+fn stable_test_1_extension() {
+    //The extension variable 7 is defined as conjunction of 4 and 6 by RAT clauses, which are used by the clause '-7 0'
+    for complete in [false, true] {
+        let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                            PathBuf::from("./tests/data/stable_1_extension.proof"), Semantics::Stable, complete);
+        assert_eq!(result.1, verifier::EXIT_CODE_OK);
+        assert_eq!(result.2.get_result(), Some((true, None)));
+
+        let instance = result.2.get_instance().unwrap();
+        assert_eq!(instance.number_of_variables, 7);
+    }
+}
+
+#[test]
+fn stable_test_1_extension_statistics() {
+    let instance = parse_proof("7 -4 -6 0\n-8 7 0\n-7 4 -7 8 0\n0\n").unwrap();
+    assert_eq!(ProofStatistics::new(&instance).extension_variables, 2);
+    assert_eq!(instance.proof_clauses[2].to_proof_line(), "-7 4 -7 8 0");
+}
+
+#[test]
+fn stable_test_1_extension_malformed() {
+    assert_eq!(parse_proof("9 0\n0\n").err().unwrap(), "The clause '9 0' refers to the extension variable '9', but extension variables need to be introduced consecutively, such that the largest valid one is '7'");
+    assert_eq!(parse_proof("7 0\nd 8 0\n0\n").err().unwrap(), "The clause 'd 8 0' refers to the extension variable '8', but extension variables need to be introduced consecutively, such that the largest valid one is '7'");
+    assert_eq!(parse_proof("d 7 0\n0\n").err().unwrap(), "The clause 'd 7 0' refers to an invalid argument '7'");
+    assert_eq!(parse_proof("i 7 4 0\n0\n").err().unwrap(), "Clause line 'i 7 4 0' is malformed, as implicit clauses need to start with an argument");
+    assert_eq!(parse_proof("7 0\n-7 4 -7 -4 0\n0\n").err().unwrap(), "The proof line '-7 4 -7 -4 0' contains a witness that assigns arguments, which is only supported for the semantics ConflictFree");
+}
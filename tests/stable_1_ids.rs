@@ -0,0 +1,64 @@
+mod common;
+
+use std::sync::Arc;
+use common::{content, create_job, data, parse_job, run_job};
+use verifier::instance_base::InstanceBase;
+use verifier::parsers::file_reader::Source;
+use verifier::semantics::Semantics;
+use verifier::supervisor::Supervisor;
+use verifier::VerificationJob;
+
+/// Creates the job for the ID-annotated proof for the stable framework.
+fn create_ids_job(proof: Source) -> VerificationJob {
+    VerificationJob { clause_ids: true, ..create_job("stable_1.ccl", proof, Semantics::Stable) }
+}
+
+/// Verifies the ID-annotated proof for the stable framework.
+fn verify_proof(proof: &str, complete: bool) -> Arc<Supervisor> {
+    run_job(VerificationJob { required_arguments: Some(data("stable_1.required")), ..create_ids_job(data(proof)) }, complete)
+}
+
+/// Parses the ID-annotated proof for the stable framework.
+fn parse_proof(proof: &str) -> Result<InstanceBase, String> {
+    parse_job(&create_ids_job(content(proof)))
+}
+
+#[test]
+fn stable_test_1_ids() {
+    let supervisor = verify_proof("stable_1_ids.proof", false);
+    assert_eq!(supervisor.get_result(), Some((true, None)));
+
+    let instance = supervisor.get_instance().unwrap();
+    assert_eq!(instance.clause_ids, Some(vec![10, 11, 12, 13, 14]));
+    assert!(instance.clause_states[1].deleted_at().is_some()); //The attack with ID 2
+    assert!(instance.clause_states[instance.proof_start].deleted_at().is_some()); //The proof clause with ID 10
+
+    let used : Vec<u64> = (instance.proof_start..instance.get_number_of_clauses()).filter(|index| instance.clause_states[*index].is_used()).map(|index| instance.get_clause_id(index).unwrap()).collect();
+    assert_eq!(used, vec![11, 12, 13]);
+}
+
+#[test]
+fn stable_test_1_ids_failed() {
+    let supervisor = verify_proof("stable_1_ids_failed.proof", true);
+    let instance = supervisor.get_instance().unwrap();
+    assert_eq!(supervisor.get_result(), Some((false, Some(instance.proof_start))));
+    assert_eq!(instance.describe_clause(instance.proof_start), "proof clause with (0-based) index 0 (ID 10)");
+}
+
+#[test]
+fn stable_test_1_ids_write() {
+    let instance = parse_proof(&std::fs::read_to_string("./tests/data/stable_1_ids.proof").unwrap()).unwrap();
+    let mut written = Vec::new();
+    instance.write_proof(&mut written, &|_| true).unwrap();
+    assert_eq!(String::from_utf8(written).unwrap(), "10 i 4 6 2 0\n11 -5 2 0\n12 i 5 4 0\nd 2 0\n13 i 6 5 0\n14 -4 -5 0\nd 10 0\n0\n");
+}
+
+#[test]
+fn stable_test_1_ids_malformed() {
+    assert_eq!(parse_proof("10 -5 2 0\n10 i 5 4 0\n0\n").err().unwrap(), "The clause ID '10' is not unique");
+    assert_eq!(parse_proof("8 -5 2 0\n0\n").err().unwrap(), "The clause ID '8' is not unique, as the IDs 1 to 8 refer to the attacks");
+    assert_eq!(parse_proof("-5 2 0\n0\n").err().unwrap(), "Clause line '-5 2 0' does not start with a valid clause ID");
+    assert_eq!(parse_proof("10 -5 2 0\nd 11 0\n0\n").err().unwrap(), "Clause deletion line 'd 11 0' references the clause with ID '11' that does not exist");
+    assert_eq!(parse_proof("10 -5 2 0\nd 10 2 10 0\n0\n").err().unwrap(), "Clause deletion line 'd 10 2 10 0' references the clause with ID '10' that has already been deleted.");
+    assert_eq!(parse_proof("10 -5 2 0\nd 0\n0\n").err().unwrap(), "Clause deletion line 'd 0' cannot be empty");
+}
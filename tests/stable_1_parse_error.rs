@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+use verifier::semantics::Semantics;
+use verifier::{verify};
+
+#[test]
+fn stable_test_1_parse_error() {
+
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                        PathBuf::from("./tests/data/stable_1_parse_error.proof"), Semantics::Stable, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_INSTANCE);
+
+    let error = result.2.get_parsing_error();
+    assert_eq!(error.file.as_deref(), Some("./tests/data/stable_1_parse_error.proof"));
+    assert_eq!(error.line_number, Some(4));
+    assert_eq!(error.column, Some(7));
+    assert_eq!(error.to_string(), "./tests/data/stable_1_parse_error.proof:4:7: error: The proof line 'i 5 4 x 0' contains an invalid argument 'x'");
+    assert_eq!(error.get_report(), format!("{}\n    i 5 4 x 0\n          ^", error));
+}
@@ -0,0 +1,56 @@
+mod common;
+
+use std::sync::Arc;
+use common::{content, create_job, data, run_job};
+use verifier::framework_base::FrameworkBase;
+use verifier::query::Query;
+use verifier::semantics::Semantics;
+use verifier::VerificationJob;
+
+/// Parses the stable framework with the names of the admissible framework, which has the same arguments.
+fn parse_framework() -> Arc<FrameworkBase> {
+    Arc::new(FrameworkBase::new(&data("stable_1.ccl"), None, &Some(data("admissible_1.description")), false).unwrap())
+}
+
+/// Verifies the proof for the query, whose required arguments are built internally.
+fn verify_query(query: &Query, proof: &str) -> Option<(bool, Option<usize>)> {
+    let job = VerificationJob { required_arguments: Some(content(&query.to_required_arguments())), ..create_job("stable_1.ccl", content(proof), Semantics::Stable) };
+    run_job(job, false).get_result()
+}
+
+#[test]
+fn stable_test_1_query_proof() {
+    let framework = parse_framework();
+    let query = Query::Credulous(Query::parse_argument("a1", &framework).unwrap());
+    assert_eq!(query, Query::Credulous(0));
+    assert_eq!(query.to_required_arguments(), "1\n");
+
+    //The proof for the required argument '1' shows that no stable extension contains it
+    let proof = std::fs::read_to_string("./tests/data/stable_1_1.proof").unwrap();
+    assert_eq!(verify_query(&query, &proof), Some((true, None)));
+    assert!(!query.answer_by_proof());
+    assert_eq!(query.describe_answer(query.answer_by_proof(), &Semantics::Stable), "The argument '1' is not credulously accepted under the semantics Stable, as no extension contains it.");
+
+    let query = Query::Skeptical(4);
+    assert_eq!(query.to_required_arguments(), "-5\n");
+    assert!(query.answer_by_proof());
+    assert_eq!(query.describe_answer(true, &Semantics::Stable), "The argument '5' is skeptically accepted under the semantics Stable, as every extension contains it.");
+}
+
+#[test]
+fn stable_test_1_query_extension() {
+    assert_eq!(Query::Credulous(1).answer_by_extension(&[1, 4]), Ok(true));
+    assert_eq!(Query::Skeptical(0).answer_by_extension(&[1, 4]), Ok(false));
+    assert_eq!(Query::Credulous(0).answer_by_extension(&[1, 4]).err().unwrap(), "The witness extension does not contain the argument '1', thus it does not show that the argument is credulously accepted");
+    assert_eq!(Query::Skeptical(1).answer_by_extension(&[1, 4]).err().unwrap(), "The witness extension contains the argument '2', thus it does not show that the argument is not skeptically accepted");
+    assert_eq!(Query::Skeptical(0).describe_answer(false, &Semantics::Admissible), "The argument '1' is not skeptically accepted under the semantics Admissible, as not every extension contains it.");
+}
+
+#[test]
+fn stable_test_1_query_malformed() {
+    let framework = parse_framework();
+    assert_eq!(Query::parse_argument("\"arg four\"", &framework).unwrap(), 3);
+    assert_eq!(Query::parse_argument("-2", &framework).err().unwrap().message, "The query refers to the negated argument '-2'");
+    assert_eq!(Query::parse_argument("7", &framework).err().unwrap().message, "The query refers to the invalid argument '7'");
+    assert_eq!(Query::parse_argument("g", &framework).err().unwrap().message, "The query refers to the invalid argument 'g'");
+}
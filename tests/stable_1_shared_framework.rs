@@ -0,0 +1,22 @@
+mod common;
+
+use std::path::PathBuf;
+use std::sync::Arc;
+use common::{create_job, data};
+use verifier::semantics::Semantics;
+use verifier::{verify, verify_with_framework, VerificationJob};
+
+#[test]
+fn stable_test_1_shared_framework(){
+    let result = verify(1, PathBuf::from("./tests/data/stable_1.ccl"), None, Some(PathBuf::from("./tests/data/stable_1.required")),
+                        PathBuf::from("./tests/data/stable_1_1.proof"), Semantics::Stable, false);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(result.2.get_result().unwrap().0);
+    let framework = Arc::clone(&result.2.get_instance().unwrap().framework);
+
+    let job = VerificationJob { required_arguments: Some(data("stable_1.required")), ..create_job("stable_1.ccl", data("stable_1_3.proof"), Semantics::Stable) };
+    let result = verify_with_framework(1, job, Some(Arc::clone(&framework)), false, None, None);
+    assert_eq!(result.1, verifier::EXIT_CODE_OK);
+    assert!(Arc::ptr_eq(&framework, &result.2.get_instance().unwrap().framework));
+    assert_eq!(result.2.get_result().unwrap().1.unwrap(), 9);
+}
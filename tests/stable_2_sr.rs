@@ -0,0 +1,43 @@
+mod common;
+
+use common::{content, create_job, parse_job, run_job};
+use verifier::instance_base::InstanceBase;
+use verifier::semantics::Semantics;
+
+/// Verifies the proof for the stable framework with two symmetric cycles.
+fn verify_proof(proof: &str) -> Option<(bool, Option<usize>)> {
+    run_job(create_job("stable_2.ccl", content(proof), Semantics::Stable), true).get_result()
+}
+
+/// Parses the proof for the stable framework with two symmetric cycles.
+fn parse_proof(proof: &str) -> Result<InstanceBase, String> {
+    parse_job(&create_job("stable_2.ccl", content(proof), Semantics::Stable))
+}
+
+#[test]
+fn stable_test_2_sr() {
+    let proof = std::fs::read_to_string("./tests/data/stable_2_sr.proof").unwrap();
+    assert_eq!(verify_proof(&proof), Some((true, None)));
+
+    //The clause is not RUP without the permutation
+    assert_eq!(verify_proof(&proof.replace("s{1:4,4:1,2:5,5:2,3:6,6:3} ", "")), Some((false, Some(6))));
+
+    //Swapping only the first arguments of the cycles does not preserve the attacks
+    assert_eq!(verify_proof(&proof.replace("s{1:4,4:1,2:5,5:2,3:6,6:3}", "s{1:4,4:1}")), Some((false, Some(6))));
+}
+
+#[test]
+fn stable_test_2_sr_write() {
+    let instance = parse_proof(&std::fs::read_to_string("./tests/data/stable_2_sr.proof").unwrap()).unwrap();
+    assert_eq!(instance.proof_clauses[0].to_proof_line(), "s{1:4,4:1,2:5,5:2,3:6,6:3} -1 4 0");
+}
+
+#[test]
+fn stable_test_2_sr_malformed() {
+    assert_eq!(parse_proof("s{1:4} -1 4 0\n0\n").err().unwrap(), "The mappings of the proof line 's{1:4} -1 4 0' do not form a permutation of the arguments");
+    assert_eq!(parse_proof("s{1:4,4:1,1:1} -1 4 0\n0\n").err().unwrap(), "The mappings of the proof line 's{1:4,4:1,1:1} -1 4 0' do not form a permutation of the arguments");
+    assert_eq!(parse_proof("s{1:-4,-4:1} -1 4 0\n0\n").err().unwrap(), "The proof line 's{1:-4,-4:1} -1 4 0' maps the negated argument '-4'");
+    assert_eq!(parse_proof("s{1} -1 4 0\n0\n").err().unwrap(), "The proof line 's{1} -1 4 0' contains the malformed mapping '1'");
+    assert_eq!(parse_proof("s{1:4,4:1} i 1 3 0\n0\n").err().unwrap(), "Clause line 's{1:4,4:1} i 1 3 0' is malformed, as implicit clauses cannot have witnesses");
+    assert_eq!(parse_proof("s{1:4,4:1} 0\n").err().unwrap(), "Clause line 's{1:4,4:1} 0' is malformed, as the empty clause cannot have a witness");
+}